@@ -0,0 +1,18 @@
+const Handlebars = require("handlebars");
+const typeConvert = require("./typeConvert");
+
+// Type of the map which collects the keys of an object that are not declared
+// in its properties.
+const additionalPropertiesType = (schema, is_cabi_str = "false", type_name) => {
+  const is_cabi = is_cabi_str === "true";
+  return new Handlebars.SafeString(
+    typeConvert(
+      { type: "object", additionalProperties: schema.additionalProperties },
+      true,
+      is_cabi,
+      type_name
+    )
+  );
+};
+
+module.exports = additionalPropertiesType;
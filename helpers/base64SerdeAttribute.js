@@ -0,0 +1,20 @@
+const Handlebars = require("handlebars");
+const isNullable = require("./isNullable");
+
+const BASE64 = "crate::formats::base64_bytes";
+
+// serde attribute of a Vec<u8> field, whichever Options wrap it.
+const base64SerdeAttribute = (prop, is_required = true) => {
+  const nullable = isNullable(prop);
+  let attribute;
+  if (is_required) {
+    attribute = `with = "${BASE64}${nullable ? "::option" : ""}"`;
+  } else {
+    attribute =
+      `default, with = "${BASE64}${nullable ? "::double_option" : "::option"}", ` +
+      `skip_serializing_if = "Option::is_none"`;
+  }
+  return new Handlebars.SafeString(`#[serde(${attribute})]`);
+};
+
+module.exports = base64SerdeAttribute;
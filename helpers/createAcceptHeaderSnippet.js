@@ -0,0 +1,21 @@
+const Handlebars = require("handlebars");
+
+// Accepts the media types which the responses of an operation declare, in the
+// order they are declared.
+const createAcceptHeaderSnippet = (operation) => {
+  const mediaTypes = new Set();
+  for (const response of Object.values(operation.responses || {})) {
+    for (const mediaType of Object.keys(response.content || {})) {
+      mediaTypes.add(mediaType);
+    }
+  }
+  if (mediaTypes.size === 0) {
+    return "";
+  }
+  const accept = [...mediaTypes].join(", ");
+  return new Handlebars.SafeString(
+    `\n.header(reqwest::header::ACCEPT, "${accept}")`
+  );
+};
+
+module.exports = createAcceptHeaderSnippet;
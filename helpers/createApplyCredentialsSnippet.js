@@ -0,0 +1,29 @@
+const Handlebars = require("handlebars");
+const getSecurityKind = require("./getSecurityKind");
+
+// Match arms sending the key of each API key scheme in the header, query
+// parameter or cookie the scheme declares.
+const createApplyCredentialsSnippet = (schemes) => {
+  let snippet = "";
+  for (const [name, scheme] of Object.entries(schemes || {})) {
+    if (getSecurityKind(scheme) !== "apiKey") {
+      continue;
+    }
+    const keyName = JSON.stringify(scheme.name);
+    let apply;
+    switch (scheme.in) {
+      case "query":
+        apply = `request.query(&[(${keyName}, key)])`;
+        break;
+      case "cookie":
+        apply = `request.header(reqwest::header::COOKIE, format!("{}={}", ${keyName}, key))`;
+        break;
+      default:
+        apply = `request.header(${keyName}, key)`;
+    }
+    snippet += `(${JSON.stringify(name)}, Credentials::Token(key)) => ${apply},\n`;
+  }
+  return new Handlebars.SafeString(snippet);
+};
+
+module.exports = createApplyCredentialsSnippet;
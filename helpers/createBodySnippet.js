@@ -0,0 +1,96 @@
+const Handlebars = require("handlebars");
+const createMultipartSnippet = require("./createMultipartSnippet");
+const getBodyMediaTypes = require("./getBodyMediaTypes");
+const getParametersByType = require("./getParametersByType");
+const getSome = require("./getSome");
+const isBinarySchema = require("./isBinarySchema");
+const toRustString = require("./toRustString");
+
+const styles = {
+  form: "Form",
+  spaceDelimited: "SpaceDelimited",
+  pipeDelimited: "PipeDelimited",
+  deepObject: "DeepObject",
+};
+
+// The `encoding` entries of a form body, explode defaults to true for the form
+// style only.
+const createEncodings = (media) => {
+  const encodings = Object.entries(media.encoding || {})
+    .filter(([, encoding]) => encoding.style || encoding.explode !== undefined)
+    .map(([name, encoding]) => {
+      const style = styles[encoding.style] || "Form";
+      const explode =
+        encoding.explode !== undefined ? encoding.explode : style === "Form";
+      return `("${name}", urlencoded::Style::${style}, ${explode})`;
+    });
+  return `&[${encodings.join(", ")}]`;
+};
+
+// The Content-Type is the one the caller chose, parameters included.
+const withContentType = () =>
+  `request.header(reqwest::header::CONTENT_TYPE, &body_content_type)`;
+
+// Evaluates to the request builder with the body, which is present, sent as
+// the media type.
+const attachBody = (operation, body, media, is_cabi_str) => {
+  const { mediaType, kind } = media;
+  switch (kind) {
+    case "json":
+      return `${withContentType()}.json(&body)`;
+    case "form":
+      return `request.form(&urlencoded::encode(&body, ${createEncodings(
+        operation.requestBody.content[mediaType]
+      )})?)`;
+    default:
+      return (
+        withContentType() +
+        (kind === "bytes" && isBinarySchema(body.schema, is_cabi_str)
+          ? ".body(body)"
+          : ".body(body.to_string())")
+      );
+  }
+};
+
+// Media types are matched by their lowercased essence, without parameters.
+const essence = (mediaType) => mediaType.split(";")[0].trim().toLowerCase();
+
+// Attaches the body parameter to the request builder, sent as the media type
+// chosen in the request options or else the preferred one of the operation.
+// Requests without their optional body are sent without one and without a
+// Content-Type.
+const createBodySnippet = (operation, schemas, is_cabi_str = "false") => {
+  const [body] = getParametersByType(operation._sortedParameters, "body");
+  const mediaTypes = getBodyMediaTypes(operation, is_cabi_str);
+  if (body === undefined || mediaTypes.length === 0) {
+    return "";
+  }
+  const seen = new Set();
+  const arms = [];
+  for (const media of mediaTypes) {
+    if (seen.has(essence(media.mediaType))) {
+      continue;
+    }
+    seen.add(essence(media.mediaType));
+    let attach;
+    if (media.kind === "multipart") {
+      attach = createMultipartSnippet(operation, schemas, is_cabi_str);
+    } else {
+      attach = attachBody(operation, body, media, is_cabi_str);
+      if (!body.required) {
+        attach = `if let ${getSome(is_cabi_str)}(body) = body { ${attach} } else { request }`;
+      }
+    }
+    arms.push(`${toRustString(essence(media.mediaType))} => ${attach},`);
+  }
+  return new Handlebars.SafeString(
+    `let body_content_type = options.content_type.clone().unwrap_or_else(|| ${toRustString(
+      mediaTypes[0].mediaType
+    )}.into());\n` +
+      `let request = match body_content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase().as_str() {\n` +
+      arms.join("\n") +
+      `\n_ => return Err(ForgeError::invalid_request(format!("the request body cannot be sent as {}", body_content_type))),\n};`
+  );
+};
+
+module.exports = createBodySnippet;
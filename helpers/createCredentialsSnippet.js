@@ -0,0 +1,42 @@
+const Handlebars = require("handlebars");
+const getSecurityKind = require("./getSecurityKind");
+const toSecurityFieldName = require("./toSecurityFieldName");
+
+// Match arms reading the credentials of each supported security scheme from
+// the Configuration. Bearer and OAuth2 schemes fall back to the generic bearer
+// token, OAuth2 ones first ask their token provider.
+const createCredentialsSnippet = (schemes, is_cabi_str = "false") => {
+  let snippet = "";
+  for (const [name, scheme] of Object.entries(schemes || {})) {
+    const kind = getSecurityKind(scheme);
+    if (kind === undefined) {
+      continue;
+    }
+    const field = `config.${toSecurityFieldName(name, schemes)}`;
+    let credentials;
+    if (kind === "basic") {
+      credentials = `${field}.clone().map(Credentials::Basic)`;
+    } else if (kind === "apiKey") {
+      credentials = `${field}.clone().map(Credentials::Token)`;
+    } else if (scheme.type !== "oauth2") {
+      credentials =
+        `${field}.clone().or_else(|| config.bearer_token.clone())` +
+        `.map(Credentials::Token)`;
+    } else {
+      const wait = is_cabi_str === "true" ? "" : ".await";
+      credentials =
+        `match (&${field}, &${field}_provider) {` +
+        `(None, Some(provider)) => {` +
+        `*renewable = true;` +
+        `Some(Credentials::Token(provider.access_token(client, &config.get_base_address(), scopes, renew)${wait}?))` +
+        `}` +
+        `(token, _) => token.clone().or_else(|| config.bearer_token.clone())` +
+        `.map(Credentials::Token),` +
+        `}`;
+    }
+    snippet += `${JSON.stringify(name)} => ${credentials},\n`;
+  }
+  return new Handlebars.SafeString(snippet);
+};
+
+module.exports = createCredentialsSnippet;
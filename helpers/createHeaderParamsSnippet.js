@@ -1,33 +1,35 @@
 const Handlebars = require("handlebars");
 const toParamName = require("./toRustParamName");
 const getParametersByType = require("./getParametersByType");
+const getObjectParamFields = require("./getObjectParamFields");
 const getSome = require("./getSome");
 
 const pushToHeaderParam = (name, value) =>
-  `headers.insert(${name}, reqwest::header::HeaderValue::from_str(&${value})?);`;
+  `headers.insert(${name}, reqwest::header::HeaderValue::from_str(&${value}).map_err(ForgeError::invalid_request)?);`;
 
 const createHeaderParamsSnippet = (sortedParams, is_cabi = false) => {
   let headerSnippet = `let mut headers = reqwest::header::HeaderMap::new();\n`;
   //Add cookie parameters
   let cookieParams = getParametersByType(sortedParams, "cookie");
   if (cookieParams.length !== 0) {
-    let cookie = "[";
+    headerSnippet +=
+      "let mut cookies: std::vec::Vec<std::string::String> = std::vec::Vec::new();\n";
     for (const cookieParam of cookieParams) {
       let safeParamName = toParamName(cookieParam.name);
-      if (cookieParam._optional) {
-        cookie +=
+      const push = `cookies.push(format!("${cookieParam.name}={}", ${safeParamName}));`;
+      if (cookieParam.required !== true) {
+        headerSnippet +=
           `if let ` +
           getSome(is_cabi) +
-          `(${safeParamName}) = ${safeParamName} { ` +
-          `format!("${cookieParam.name}={}",${safeParamName})` +
-          `} else { "".into() } ,`;
+          `(${safeParamName}) = &${safeParamName} { ${push} }\n`;
       } else {
-        `&format!("${cookieParam.name}={}",${safeParamName}),`;
+        headerSnippet += `${push}\n`;
       }
     }
-    cookie += `].join(";")\n`;
-    headerSnippet += pushToHeaderParam(`reqwest::header::COOKIE`, cookie);
-    headerSnippet += "\n";
+    headerSnippet +=
+      `if !cookies.is_empty() { ` +
+      pushToHeaderParam(`reqwest::header::COOKIE`, `cookies.join(";")`) +
+      ` }\n`;
   }
 
   const headerParams = getParametersByType(sortedParams, "header");
@@ -48,13 +50,31 @@ const createHeaderParamsSnippet = (sortedParams, is_cabi = false) => {
         );
         break;
       case "object": {
-        let serialisedObject = "";
-        for (const [propName] of Object.entries(
-          headerParam.schema.properties
+        // the properties are sent as comma separated name,value pairs
+        let serialisedObject =
+          "let mut values: std::vec::Vec<std::string::String> = std::vec::Vec::new();";
+        for (const { name, field, options } of getObjectParamFields(
+          headerParam.schema,
+          is_cabi
         )) {
-          serialisedObject += `${propName},${safeParamName}.${propName}`;
+          const push = `values.push(format!("${name},{}", value));`;
+          if (options === 0) {
+            serialisedObject += `{ let value = &${safeParamName}.${field}; ${push} }`;
+          } else {
+            const pattern =
+              `${getSome(is_cabi)}(`.repeat(options) +
+              "value" +
+              ")".repeat(options);
+            serialisedObject += `if let ${pattern} = &${safeParamName}.${field} { ${push} }`;
+          }
         }
-        headerSnippet += pushToHeaderParam(headerParam.name, serialisedObject);
+        serialisedObject += pushToHeaderParam(
+          `"${headerParam.name}"`,
+          `values.join(",")`
+        );
+        headerSnippet += headerParam.required
+          ? `{ ${serialisedObject} }`
+          : `if let ${getSome(is_cabi)}(${safeParamName}) = &${safeParamName} { ${serialisedObject} }`;
         break;
       }
       default: {
@@ -63,12 +83,15 @@ const createHeaderParamsSnippet = (sortedParams, is_cabi = false) => {
             `if let ` +
             getSome(is_cabi) +
             `(${safeParamName}) = ${safeParamName} { ` +
-            pushToHeaderParam(`"${headerParam.name}"`, safeParamName) +
+            pushToHeaderParam(
+              `"${headerParam.name}"`,
+              `${safeParamName}.to_string()`
+            ) +
             `}`;
         } else {
           headerSnippet += pushToHeaderParam(
             `"${headerParam.name}"`,
-            safeParamName
+            `${safeParamName}.to_string()`
           );
         }
       }
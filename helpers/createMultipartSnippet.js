@@ -0,0 +1,48 @@
+const Handlebars = require("handlebars");
+const getMultipartFields = require("./getMultipartFields");
+const getParametersByType = require("./getParametersByType");
+const getSome = require("./getSome");
+
+const createPart = (field, value, is_cabi_str) =>
+  field.file
+    ? `${value}.into_part("${field.contentType}")` +
+      (is_cabi_str === "true" ? "" : ".await") +
+      "?"
+    : `multipart::value_part(${value}, "${field.contentType}")?`;
+
+const addPart = (field, value, is_cabi_str) =>
+  `form = form.part("${field.name}", ${createPart(field, value, is_cabi_str)});`;
+
+// Files are moved into their parts, values are serialized by reference.
+const addField = (field, is_cabi_str) => {
+  const source = (field.file ? "" : "&") + `body.${field.field}`;
+  const add = field.array
+    ? `for value in values { ${addPart(field, "value", is_cabi_str)} }`
+    : addPart(field, "value", is_cabi_str);
+  const bind = field.array ? "values" : "value";
+  if (field.optional) {
+    return `if let ${getSome(is_cabi_str)}(${bind}) = ${source} { ${add} }`;
+  }
+  return field.array
+    ? `for value in ${source} { ${addPart(field, "value", is_cabi_str)} }`
+    : addPart(field, source, is_cabi_str);
+};
+
+// Sends the form struct of the body parameter as a multipart/form-data body,
+// a part per field. Evaluates to the request builder with the body attached.
+const createMultipartSnippet = (operation, schemas, is_cabi_str = "false") => {
+  const [body] = getParametersByType(operation._sortedParameters, "body");
+  const fields = getMultipartFields(operation, schemas, is_cabi_str);
+  const form =
+    `{ let ${fields.length > 0 ? "mut " : ""}form = multipart::Form::new();\n` +
+    fields.map((field) => addField(field, is_cabi_str) + "\n").join("") +
+    "request.multipart(form) }";
+  if (body.required) {
+    return new Handlebars.SafeString(form);
+  }
+  return new Handlebars.SafeString(
+    `match body { ${getSome(is_cabi_str)}(body) => ${form} _ => request }`
+  );
+};
+
+module.exports = createMultipartSnippet;
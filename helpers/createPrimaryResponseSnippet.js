@@ -0,0 +1,33 @@
+const Handlebars = require("handlebars");
+const getResponseVariants = require("./getResponseVariants");
+const toClassName = require("./toClassName");
+
+// Unwraps the variant of the response openapi-forge picked as `_response`
+// from `response`, as the C ABI functions only return that one. Any other
+// variant gives None, a panic would abort the caller.
+const createPrimaryResponseSnippet = (operation, is_cabi_str = "false") => {
+  const enumName = `${toClassName(operation.operationId)}Response`;
+  const variants = getResponseVariants(operation, is_cabi_str);
+  const primary = variants.find((variant) => variant.primary);
+  let snippet = "match response.data {\n";
+  if (primary) {
+    const fields = [];
+    if (primary.has_status) {
+      fields.push("_");
+    }
+    if (primary.type) {
+      fields.push("data");
+    }
+    snippet +=
+      `${enumName}::${primary.name}` +
+      (fields.length ? `(${fields.join(", ")})` : "") +
+      ` => Some(Box::new(ForgeResponse::new(${primary.type ? "data" : "()"}, ` +
+      `response.status_code, response.headers))),\n`;
+  }
+  if (!primary || variants.length > 1) {
+    snippet += `_ => None,\n`;
+  }
+  return new Handlebars.SafeString(snippet + "}");
+};
+
+module.exports = createPrimaryResponseSnippet;
@@ -1,19 +1,17 @@
 const Handlebars = require("handlebars");
 const toRustParamName = require("./toRustParamName");
 const toParamName = require("./toParamName");
+const getObjectParamFields = require("./getObjectParamFields");
 const getParametersByType = require("./getParametersByType");
 const getSome = require("./getSome");
 
-const isRequired = (typeDef) => {
-  return typeof typeDef._required !== "undefined";
-};
-
+// values are pushed through to_string, as format types such as uuids are not strings
 const pushToQueryParam = (name, value, is_cabi_str = false) => {
   const optionalToString = is_cabi_str === "true" ? ".to_string()" : "";
   const optionalInto = is_cabi_str === "true" ? ".into()" : "";
   return `query_params.push(("${name}"${optionalToString}.into(), ${toRustParamName(
     value
-  )}${optionalToString}${optionalInto}));`;
+  )}.to_string()${optionalInto}));`;
 };
 
 const serialiseArrayParam = (param, is_cabi = false) => {
@@ -25,34 +23,31 @@ const serialiseArrayParam = (param, is_cabi = false) => {
   return serialisedParam;
 };
 
+// object parameters are exploded, every property is a query parameter of its own
 const serialiseObjectParam = (param, is_required = false, is_cabi = false) => {
-  const safeParamName = toParamName(param.name);
+  const safeParamName = toRustParamName(param.name);
   let serialisedObject = "";
-  for (const [propName, objProp] of Object.entries(param.schema.properties)) {
-    let res = "";
-    if (!isRequired(objProp)) {
-      res =
-        `if let ` +
-        getSome(is_cabi) +
-        `(${toRustParamName(propName)}) = &${safeParamName}.${toRustParamName(
-          propName
-        )} { ` +
-        pushToQueryParam(propName, propName, is_cabi) +
-        ` }`;
-    } else {
-      res = pushToQueryParam(propName, `${safeParamName}.${propName}`, is_cabi);
-    }
-
-    if (!is_required) {
-      serialisedObject +=
-        `if let ` +
-        getSome(is_cabi) +
-        `(${safeParamName}) = &${safeParamName} { ${res}  }`;
+  for (const { name, field, options } of getObjectParamFields(
+    param.schema,
+    is_cabi
+  )) {
+    const push = pushToQueryParam(name, "value", is_cabi);
+    if (options === 0) {
+      serialisedObject += `{ let value = &${safeParamName}.${field}; ${push} }`;
     } else {
-      serialisedObject += res;
+      const pattern =
+        `${getSome(is_cabi)}(`.repeat(options) + "value" + ")".repeat(options);
+      serialisedObject += `if let ${pattern} = &${safeParamName}.${field} { ${push} }`;
     }
   }
 
+  if (!is_required) {
+    return (
+      `if let ` +
+      getSome(is_cabi) +
+      `(${safeParamName}) = &${safeParamName} { ${serialisedObject} }`
+    );
+  }
   return serialisedObject;
 };
 
@@ -0,0 +1,51 @@
+const Handlebars = require("handlebars");
+const createValidationSnippet = require("./createValidationSnippet");
+const getBodyMediaType = require("./getBodyMediaType");
+const toParamTypeName = require("./toParamTypeName");
+const toRustParamName = require("./toRustParamName");
+const typeConvert = require("./typeConvert");
+
+// Checks the parameters of an operation against their schema constraints before
+// the request is sent, each parameter is a token of the JSON pointers reported.
+// Multipart bodies are passed as their form struct, which has no constraints.
+const createRequestValidationSnippet = (operation, is_cabi_str = "false") => {
+  const is_cabi = is_cabi_str === "true";
+  const { _sortedParameters: params, operationId } = operation;
+  const multipart =
+    getBodyMediaType(operation, is_cabi_str) === "multipart/form-data";
+  let checks = "";
+  for (const param of params) {
+    if (multipart && param.in === "body") {
+      continue;
+    }
+    checks += createValidationSnippet(
+      param.schema,
+      toRustParamName(param.name),
+      param.name,
+      typeConvert(
+        param.schema,
+        param.required === true,
+        is_cabi,
+        toParamTypeName(operationId, param)
+      ),
+      is_cabi_str,
+      true
+    );
+  }
+  if (checks === "") {
+    return "";
+  }
+  return new Handlebars.SafeString(
+    `if self.config.validate_requests {\n` +
+      `let pointer = "";\n` +
+      `let mut violations = std::vec::Vec::new();\n` +
+      `let violations = &mut violations;\n` +
+      checks +
+      `if !violations.is_empty() {` +
+      `return Err(ValidationError::new(std::mem::take(violations)).into());` +
+      `}` +
+      `}`
+  );
+};
+
+module.exports = createRequestValidationSnippet;
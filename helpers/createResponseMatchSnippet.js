@@ -0,0 +1,55 @@
+const Handlebars = require("handlebars");
+const getResponseVariants = require("./getResponseVariants");
+const toClassName = require("./toClassName");
+
+const decodeSnippet = (variant, is_cabi) => {
+  const mediaType = `"${variant.media_type}"`;
+  switch (variant.decode) {
+    case "bytes":
+      return "body.into_bytes()";
+    case "text":
+      // The C ABI uses RString rather than std's String.
+      return `body.text(${mediaType})?` + (is_cabi ? ".into()" : "");
+    case "parse":
+      return `body.parse::<${variant.type}>(${mediaType})?`;
+    default:
+      return `body.json::<${variant.type}>(${mediaType})?`;
+  }
+};
+
+// Decodes the body into the variant of its status code, as the Content-Type of
+// the response tells. Statuses which are not documented are an error, as are
+// error statuses which only the default response documents, unless the
+// configuration opts out. They then go to the default or the `Other` variant.
+const createResponseMatchSnippet = (operation, is_cabi_str = "false") => {
+  const is_cabi = is_cabi_str === "true";
+  const enumName = `${toClassName(operation.operationId)}Response`;
+  const variants = getResponseVariants(operation, is_cabi_str);
+  let snippet = "let data = match status_code {\n";
+  for (const variant of variants) {
+    const fields = [];
+    if (variant.has_status) {
+      fields.push("status_code");
+    }
+    if (variant.type) {
+      fields.push(decodeSnippet(variant, is_cabi));
+    }
+    const pattern =
+      variant.status === "default"
+        ? "_ if (200..=299).contains(&status_code) " +
+          "|| !self.config.error_for_status"
+        : variant.other
+        ? "_ if !self.config.error_for_status"
+        : variant.pattern;
+    snippet +=
+      `${pattern} => ${enumName}::${variant.name}` +
+      (fields.length ? `(${fields.join(", ")})` : "") +
+      ",\n";
+  }
+  snippet +=
+    "_ => return Err(" +
+    "ForgeError::unexpected_status(status_code, headers, body.as_bytes())),\n";
+  return new Handlebars.SafeString(snippet + "};");
+};
+
+module.exports = createResponseMatchSnippet;
@@ -0,0 +1,35 @@
+const Handlebars = require("handlebars");
+const getSecurityKind = require("./getSecurityKind");
+
+// The security requirements of an operation, or else of the whole API, as a
+// `&[SecurityRequirement]`. Requirements naming a scheme which is not
+// supported can never be met and are left out.
+const createSecurityRequirementsSnippet = (operation, schemes, rootSecurity) => {
+  const requirements = operation.security || rootSecurity;
+  if (!Array.isArray(requirements) || !schemes || typeof schemes !== "object") {
+    return "&[]";
+  }
+  const supported = requirements
+    .map((requirement) => Object.entries(requirement || {}))
+    .filter(
+      (requirement) =>
+        requirement.length > 0 &&
+        requirement.every(([name]) => getSecurityKind(schemes[name]))
+    )
+    .map(
+      (requirement) =>
+        "&[" +
+        requirement
+          .map(
+            ([name, scopes]) =>
+              `(${JSON.stringify(name)}, &[${(scopes || [])
+                .map((scope) => JSON.stringify(scope))
+                .join(", ")}])`
+          )
+          .join(", ") +
+        "]"
+    );
+  return new Handlebars.SafeString(`&[${supported.join(", ")}]`);
+};
+
+module.exports = createSecurityRequirementsSnippet;
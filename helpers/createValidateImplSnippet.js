@@ -0,0 +1,92 @@
+const Handlebars = require("handlebars");
+const createValidationSnippet = require("./createValidationSnippet");
+const fieldTypeConvert = require("./fieldTypeConvert");
+const additionalPropertiesType = require("./additionalPropertiesType");
+const getUnionVariants = require("./getUnionVariants");
+const isEnumSchema = require("./isEnumSchema");
+const isRequiredField = require("./isRequiredField");
+const toClassName = require("./toClassName");
+const toInlineTypeName = require("./toInlineTypeName");
+const toRustFieldName = require("./toRustFieldName");
+
+const structChecks = (schema, name, is_cabi_str) => {
+  let checks = "";
+  for (const flattened of schema._flattened || []) {
+    checks += `self.${flattened.name}.collect_violations(pointer, violations);\n`;
+  }
+  for (const [key, prop] of Object.entries(schema.properties || {})) {
+    const is_required = isRequiredField(schema.required, key, prop);
+    checks += createValidationSnippet(
+      prop,
+      `self.${toRustFieldName(key, schema.properties, schema)}`,
+      key,
+      String(
+        fieldTypeConvert(
+          prop,
+          is_required,
+          is_cabi_str,
+          toInlineTypeName(name, key)
+        )
+      ),
+      is_cabi_str
+    );
+  }
+  if (schema.additionalProperties) {
+    checks += createValidationSnippet(
+      { type: "object", additionalProperties: schema.additionalProperties },
+      "self.additional_properties",
+      null,
+      String(additionalPropertiesType(schema, is_cabi_str, name)),
+      is_cabi_str
+    );
+  }
+  return checks;
+};
+
+const unionChecks = (schema, name, is_cabi_str, schemas) => {
+  const members = schema.oneOf || schema.anyOf;
+  let checked = false;
+  const arms = getUnionVariants(schema, is_cabi_str, name, schemas).map(
+    (variant, index) => {
+      const checks = createValidationSnippet(
+        members[index],
+        null,
+        null,
+        variant.type,
+        is_cabi_str
+      );
+      checked = checked || checks !== "";
+      return checks === ""
+        ? `Self::${variant.name}(_) => {}\n`
+        : `Self::${variant.name}(value) => ${checks}`;
+    }
+  );
+  return checked ? `match self { ${arms.join(" ")} }` : "";
+};
+
+// The Validate implementation of a generated model, enums have no constraints
+// beyond those their type already enforces.
+const createValidateImplSnippet = (
+  schema,
+  name,
+  is_cabi_str = "false",
+  schemas = {}
+) => {
+  let checks = "";
+  if (schema.oneOf || schema.anyOf) {
+    checks = unionChecks(schema, name, is_cabi_str, schemas);
+  } else if (!isEnumSchema(schema)) {
+    checks = structChecks(schema, name, is_cabi_str);
+  }
+  const params =
+    checks !== "" ? "pointer: &str, violations" : "_pointer: &str, _violations";
+  return new Handlebars.SafeString(
+    `impl Validate for ${toClassName(name)} {` +
+      `fn collect_violations(&self, ${params}: &mut std::vec::Vec<ConstraintViolation>) {\n` +
+      checks +
+      `}` +
+      `}`
+  );
+};
+
+module.exports = createValidateImplSnippet;
@@ -0,0 +1,243 @@
+const typeConvert = require("./typeConvert");
+const isEnumSchema = require("./isEnumSchema");
+const isNullable = require("./isNullable");
+const needsNamedType = require("./needsNamedType");
+const withoutNull = require("./withoutNull");
+const getSome = require("./getSome");
+const isByteField = require("./isByteField");
+const toRustString = require("./toRustString");
+
+const NUMERIC_TYPES = ["i32", "i64", "f32", "f64", "rust_decimal::Decimal"];
+
+// Patterns are emitted as raw strings, so that their backslashes survive.
+const toStringLiteral = (value) =>
+  value.includes('"#') ? toRustString(value).toString() : `r#"${value}"#`;
+
+const toFloatLiteral = (value) => {
+  const literal = String(value);
+  return /[.e]/.test(literal) ? literal : `${literal}.0`;
+};
+
+const violation = (constraint, message) =>
+  `violations.push(ConstraintViolation::new(pointer, "${constraint}", ${toStringLiteral(
+    message
+  )}));`;
+
+const check = (condition, constraint, message) =>
+  `if ${condition} { ${violation(constraint, message)} }\n`;
+
+const stringChecks = (schema) => {
+  let checks = "";
+  if (schema.minLength !== undefined) {
+    checks += check(
+      `value.chars().count() < ${schema.minLength}`,
+      "minLength",
+      `must be at least ${schema.minLength} characters long`
+    );
+  }
+  if (schema.maxLength !== undefined) {
+    checks += check(
+      `value.chars().count() > ${schema.maxLength}`,
+      "maxLength",
+      `must be at most ${schema.maxLength} characters long`
+    );
+  }
+  // Each pattern is compiled once, into a static of its own.
+  if (schema.pattern !== undefined) {
+    checks +=
+      `{ static PATTERN: std::sync::OnceLock<Option<regex::Regex>> = std::sync::OnceLock::new();\n` +
+      `match compiled_pattern(&PATTERN, ${toStringLiteral(schema.pattern)}) {\n` +
+      `Some(regex) => ${check(
+        "!regex.is_match(value.as_str())",
+        "pattern",
+        `must match the pattern ${schema.pattern}`
+      )}` +
+      `None => { ${violation(
+        "pattern",
+        `the pattern ${schema.pattern} is not supported`
+      )} }\n` +
+      `} }\n`;
+  }
+  return checks;
+};
+
+// Handles both the boolean exclusiveMinimum of OpenAPI 3.0 and the numeric one of 3.1.
+const numberChecks = (schema) => {
+  let checks = "";
+  const bound = (keyword, exclusiveKeyword, operator, words) => {
+    const exclusive = schema[exclusiveKeyword];
+    if (typeof exclusive === "number") {
+      checks += check(
+        `Numeric::to_f64(value) ${operator}= ${toFloatLiteral(exclusive)}`,
+        exclusiveKeyword,
+        `must be ${words} ${exclusive}`
+      );
+    }
+    if (schema[keyword] !== undefined) {
+      checks +=
+        exclusive === true
+          ? check(
+              `Numeric::to_f64(value) ${operator}= ${toFloatLiteral(
+                schema[keyword]
+              )}`,
+              exclusiveKeyword,
+              `must be ${words} ${schema[keyword]}`
+            )
+          : check(
+              `Numeric::to_f64(value) ${operator} ${toFloatLiteral(
+                schema[keyword]
+              )}`,
+              keyword,
+              `must be ${words} or equal to ${schema[keyword]}`
+            );
+    }
+  };
+  bound("minimum", "exclusiveMinimum", "<", "greater than");
+  bound("maximum", "exclusiveMaximum", ">", "less than");
+  if (schema.multipleOf !== undefined) {
+    checks += check(
+      `!is_multiple_of(Numeric::to_f64(value), ${toFloatLiteral(
+        schema.multipleOf
+      )})`,
+      "multipleOf",
+      `must be a multiple of ${schema.multipleOf}`
+    );
+  }
+  return checks;
+};
+
+const arrayChecks = (schema, is_cabi, is_operation) => {
+  let checks = "";
+  if (schema.minItems !== undefined) {
+    checks += check(
+      schema.minItems === 1
+        ? `value.is_empty()`
+        : `value.len() < ${schema.minItems}`,
+      "minItems",
+      `must contain at least ${schema.minItems} items`
+    );
+  }
+  if (schema.maxItems !== undefined) {
+    checks += check(
+      `value.len() > ${schema.maxItems}`,
+      "maxItems",
+      `must contain at most ${schema.maxItems} items`
+    );
+  }
+  if (schema.uniqueItems === true) {
+    checks += check(
+      `!unique_items(value)`,
+      "uniqueItems",
+      "must not contain duplicate items"
+    );
+  }
+  const itemChecks = valueChecks(schema.items, is_cabi, is_operation);
+  if (itemChecks !== "") {
+    checks +=
+      `for (index, value) in value.iter().enumerate() {\n` +
+      `let pointer = &join_pointer(pointer, &index.to_string());` +
+      itemChecks +
+      `}\n`;
+  }
+  return checks;
+};
+
+const mapChecks = (schema, is_cabi, is_operation) => {
+  const entryChecks = valueChecks(
+    schema.additionalProperties,
+    is_cabi,
+    is_operation
+  );
+  if (entryChecks === "") {
+    return "";
+  }
+  return (
+    `for entry in value.iter() {\n` +
+    `let pointer = &join_pointer(pointer, entry.0.as_str());` +
+    `let value = entry.1;` +
+    entryChecks +
+    `}\n`
+  );
+};
+
+// Checks on `value`, a reference to the Rust value generated for `schema`.
+// Operation parameters keep their enums as plain strings and integers.
+const valueChecks = (schema, is_cabi, is_operation) => {
+  if (!schema || typeof schema !== "object") {
+    return "";
+  }
+  if (isNullable(schema)) {
+    const checks = valueChecks(withoutNull(schema), is_cabi, is_operation);
+    const some = getSome(is_cabi ? "true" : "false");
+    return checks === ""
+      ? ""
+      : `if let ${some}(value) = value { ${checks} }\n`;
+  }
+  if (
+    schema.$ref ||
+    (needsNamedType(schema) && !(is_operation && isEnumSchema(schema)))
+  ) {
+    return `value.collect_violations(pointer, violations);\n`;
+  }
+  const members = schema.oneOf || schema.anyOf || schema.allOf;
+  if (members) {
+    return members.length === 1
+      ? valueChecks(members[0], is_cabi, is_operation)
+      : "";
+  }
+  const type = typeConvert(schema, true, is_cabi);
+  if (type === "String" && !isByteField(schema, is_cabi ? "true" : "false")) {
+    return stringChecks(schema);
+  }
+  if (NUMERIC_TYPES.includes(type)) {
+    return numberChecks(schema);
+  }
+  if (schema.type === "array") {
+    return arrayChecks(schema, is_cabi, is_operation);
+  }
+  if (
+    type.startsWith("HashMap<") &&
+    typeof schema.additionalProperties === "object"
+  ) {
+    return mapChecks(schema, is_cabi, is_operation);
+  }
+  return "";
+};
+
+// Validation of a single field or parameter: `value` is the Rust expression
+// holding it, null when a reference to it is already bound to `value`,
+// `rust_type` its type and `token` its name within the JSON pointer, null for
+// values flattened into their parent.
+// Returns an empty string when the schema declares nothing to check.
+const createValidationSnippet = (
+  schema,
+  value,
+  token,
+  rust_type,
+  is_cabi_str = "false",
+  is_operation = false
+) => {
+  const is_cabi = is_cabi_str === "true";
+  const checks = valueChecks(withoutNull(schema), is_cabi, is_operation);
+  if (checks === "") {
+    return "";
+  }
+  let pattern = "value";
+  for (let type = rust_type; type.startsWith("Option<"); type = type.slice(7)) {
+    pattern = `${getSome(is_cabi_str)}(${pattern})`;
+  }
+  const reference = value === null ? "value" : `&${value}`;
+  let binding;
+  if (pattern !== "value") {
+    binding = `if let ${pattern} = ${reference} { ${checks} }`;
+  } else {
+    binding = value === null ? checks : `let value = ${reference}; ${checks}`;
+  }
+  return token === null
+    ? `{ ${binding} }\n`
+    : `{ let pointer = &join_pointer(pointer, ${toRustString(
+        token
+      )}); ${binding} }\n`;
+};
+
+module.exports = createValidationSnippet;
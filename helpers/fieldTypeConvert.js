@@ -0,0 +1,39 @@
+const Handlebars = require("handlebars");
+const typeConvert = require("./typeConvert");
+const isNullable = require("./isNullable");
+const isByteField = require("./isByteField");
+const toBoxedType = require("./toBoxedType");
+
+// Every optional field is an Option, whichever type typeConvert picks for it.
+// Base64 encoded fields are decoded into a `Vec<u8>`.
+// Optional nullable fields become `Option<Option<T>>`, so that an absent field
+// can be told apart from an explicit null. Recursive fields are boxed.
+const fieldTypeConvert = (
+  prop,
+  is_required = true,
+  is_cabi_str = "false",
+  type_name = undefined,
+  is_recursive = false
+) => {
+  if (typeof is_required !== "boolean") {
+    is_required = true;
+  }
+  if (typeof type_name !== "string") {
+    type_name = undefined;
+  }
+  const is_cabi = is_cabi_str === "true";
+  let type = typeConvert(prop, is_required, is_cabi, type_name);
+  if (isByteField(prop, is_cabi_str)) {
+    type = isNullable(prop) ? "Option<Vec<u8>>" : "Vec<u8>";
+  }
+  const wrap =
+    !is_required && (isNullable(prop) || !type.startsWith("Option<"));
+  if (wrap) {
+    type = `Option<${type}>`;
+  }
+  return new Handlebars.SafeString(
+    is_recursive === true ? toBoxedType(type) : type
+  );
+};
+
+module.exports = fieldTypeConvert;
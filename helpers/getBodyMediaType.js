@@ -0,0 +1,10 @@
+const getBodyMediaTypes = require("./getBodyMediaTypes");
+
+// The media type the request body of an operation is sent as by default,
+// undefined when it has no body or none of its media types is supported.
+const getBodyMediaType = (operation, is_cabi_str = "false") => {
+  const [media] = getBodyMediaTypes(operation, is_cabi_str);
+  return media && media.mediaType;
+};
+
+module.exports = getBodyMediaType;
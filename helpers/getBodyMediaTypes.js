@@ -0,0 +1,69 @@
+const getMediaTypeKind = require("./getMediaTypeKind");
+const getParametersByType = require("./getParametersByType");
+const isBinarySchema = require("./isBinarySchema");
+const needsNamedType = require("./needsNamedType");
+const withoutNull = require("./withoutNull");
+
+// Well-known media types come first, the others keep their declared order.
+const preferred = [
+  "application/json",
+  "+json",
+  "text/plain",
+  "application/x-www-form-urlencoded",
+  "application/octet-stream",
+  "multipart/form-data",
+];
+
+const rank = ({ mediaType, kind }) => {
+  const index = preferred.indexOf(
+    kind === "json" && mediaType !== "application/json" ? "+json" : mediaType
+  );
+  return index === -1 ? preferred.length : index;
+};
+
+// Values which are sent as their string representation.
+const isScalar = (schema) => {
+  schema = withoutNull(schema);
+  return (
+    !!schema &&
+    !schema.$ref &&
+    !needsNamedType(schema) &&
+    ["string", "number", "integer", "boolean"].includes(schema.type)
+  );
+};
+
+const supports = (kind, schema, is_cabi_str) => {
+  const binary = isBinarySchema(schema, is_cabi_str);
+  switch (kind) {
+    case "json":
+      return !binary;
+    case "form":
+      return !binary && !isScalar(schema);
+    case "text":
+      return !binary && isScalar(schema);
+    case "bytes":
+      return binary || isScalar(schema);
+    default:
+      return true;
+  }
+};
+
+// The media types which the body parameter of an operation can be sent as, the
+// first one is sent unless the caller chooses another one. A multipart body is
+// passed as a form struct, so it is never offered along with other media types.
+const getBodyMediaTypes = (operation, is_cabi_str = "false") => {
+  const content = (operation.requestBody && operation.requestBody.content) || {};
+  const [body] = getParametersByType(operation._sortedParameters, "body");
+  const schema = body && body.schema;
+  const mediaTypes = Object.keys(content)
+    .map((mediaType) => ({ mediaType, kind: getMediaTypeKind(mediaType) }))
+    .filter(({ kind }) => supports(kind, schema, is_cabi_str))
+    .map((media, index) => Object.assign(media, { index }))
+    .sort((a, b) => rank(a) - rank(b) || a.index - b.index);
+  if (mediaTypes.length > 0 && mediaTypes[0].kind === "multipart") {
+    return mediaTypes.slice(0, 1);
+  }
+  return mediaTypes.filter(({ kind }) => kind !== "multipart");
+};
+
+module.exports = getBodyMediaTypes;
@@ -0,0 +1,31 @@
+const toInlineTypeName = require("./toInlineTypeName");
+const toRustString = require("./toRustString");
+
+const toVariantName = (value) => {
+  const name = toInlineTypeName("", String(value).replace(/^-/, "Minus "));
+  return /^[A-Za-z]/.test(name) ? name : "Value" + name;
+};
+
+// Returns the variants of an enum schema as { name, literal, text } objects,
+// where text is the value as a string literal. `Unknown` is kept free for the
+// catch-all variant.
+const getEnumVariants = (schema) => {
+  const usedNames = new Set(["Unknown"]);
+  return schema.enum
+    .filter((value) => value !== null)
+    .map((value, index) => {
+      let name = toVariantName(value);
+      if (usedNames.has(name)) {
+        name += index + 1;
+      }
+      usedNames.add(name);
+      return {
+        name,
+        literal:
+          typeof value === "string" ? String(toRustString(value)) : value,
+        text: String(toRustString(value)),
+      };
+    });
+};
+
+module.exports = getEnumVariants;
@@ -0,0 +1,111 @@
+const mergeAllOf = require("./mergeAllOf");
+const needsNamedType = require("./needsNamedType");
+const needsOperationType = require("./needsOperationType");
+const toClassName = require("./toClassName");
+const toInlineTypeName = require("./toInlineTypeName");
+const withoutNull = require("./withoutNull");
+
+// Names the inline schema at `propName` of `parentName`, adding a numeric
+// suffix when a component schema or another inline schema has the name.
+const claimName = (context, parentName, propName) => {
+  const owner = JSON.stringify([parentName, propName]);
+  const natural = toInlineTypeName(parentName, propName);
+  let name = natural;
+  for (
+    let suffix = 2;
+    context.names.has(name) && context.names.get(name) !== owner;
+    suffix++
+  ) {
+    name = natural + suffix;
+  }
+  context.names.set(name, owner);
+  if (name !== natural) {
+    toInlineTypeName.rename(parentName, propName, name);
+  }
+  return name;
+};
+
+const collect = (context, schema, parentName, propName) => {
+  if (!schema || schema.$ref) {
+    return;
+  }
+  schema = withoutNull(schema);
+  const members = schema.oneOf || schema.anyOf || schema.allOf;
+  if (members && members.length === 1) {
+    collect(context, members[0], parentName, propName);
+  } else if (schema.type === "array") {
+    const typeName = toInlineTypeName(parentName, propName);
+    collect(context, schema.items, typeName, "item");
+  } else if (needsNamedType(schema)) {
+    const typeName = claimName(context, parentName, propName);
+    context.inlineSchemas[typeName] = schema;
+    collectChildren(context, schema, typeName);
+  } else if (typeof schema.additionalProperties === "object") {
+    const typeName = toInlineTypeName(parentName, propName);
+    collect(context, schema.additionalProperties, typeName, "value");
+  }
+};
+
+const collectChildren = (context, schema, typeName) => {
+  const members = schema.oneOf || schema.anyOf;
+  if (members) {
+    members.forEach((member, index) =>
+      collect(context, member, typeName, `variant ${index + 1}`)
+    );
+  } else if (schema.type === "array") {
+    collect(context, schema.items, typeName, "item");
+  } else {
+    const properties = mergeAllOf(schema, context.schemas).properties || {};
+    for (const [propName, prop] of Object.entries(properties)) {
+      collect(context, prop, typeName, propName);
+    }
+    if (typeof schema.additionalProperties === "object") {
+      collect(context, schema.additionalProperties, typeName, "value");
+    }
+  }
+};
+
+const collectOperation = (context, operation) => {
+  const params = operation._sortedParameters || operation.parameters || [];
+  for (const param of params) {
+    if (needsOperationType(param.schema)) {
+      collect(context, param.schema, operation.operationId, param.name);
+    }
+  }
+  for (const [status, response] of Object.entries(operation.responses || {})) {
+    for (const media of Object.values(response.content || {})) {
+      if (needsOperationType(media.schema)) {
+        const propName = `${status} response`;
+        collect(context, media.schema, operation.operationId, propName);
+      }
+    }
+  }
+};
+
+// Collects the inline schemas at any depth of the models and operations which
+// need a generated type of their own, keyed by the name typeConvert gives them.
+// Their names are picked here, so it runs before any type is named.
+const getInlineSchemas = (schemas, inlineObjects, paths) => {
+  const allSchemas = Object.assign({}, schemas, inlineObjects);
+  const context = {
+    schemas: schemas || {},
+    inlineSchemas: {},
+    names: new Map(
+      Object.keys(allSchemas).map((name) => [toClassName(name), null])
+    ),
+  };
+  toInlineTypeName.clearRenames();
+  for (const [name, schema] of Object.entries(allSchemas)) {
+    collectChildren(context, withoutNull(schema), name);
+  }
+  for (const pathItem of Object.values(paths || {})) {
+    for (const operation of Object.values(pathItem)) {
+      if (operation && typeof operation === "object" && operation.responses) {
+        collectOperation(context, operation);
+      }
+    }
+  }
+  return context.inlineSchemas;
+};
+
+module.exports = getInlineSchemas;
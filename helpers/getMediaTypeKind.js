@@ -0,0 +1,24 @@
+// How a body of a media type is encoded, the same way requests are sent and
+// responses are decoded: json, form, multipart, text or else bytes.
+const getMediaTypeKind = (mediaType) => {
+  const type = mediaType.split(";")[0].trim().toLowerCase();
+  if (type === "application/json" || type.endsWith("+json")) {
+    return "json";
+  }
+  if (type === "application/x-www-form-urlencoded") {
+    return "form";
+  }
+  if (type === "multipart/form-data") {
+    return "multipart";
+  }
+  if (
+    type.startsWith("text/") ||
+    type === "application/xml" ||
+    type.endsWith("+xml")
+  ) {
+    return "text";
+  }
+  return "bytes";
+};
+
+module.exports = getMediaTypeKind;
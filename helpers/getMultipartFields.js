@@ -0,0 +1,70 @@
+const mergeAllOf = require("./mergeAllOf");
+const toRustFieldName = require("./toRustFieldName");
+const typeConvert = require("./typeConvert");
+const withoutNull = require("./withoutNull");
+
+const resolve = (schema, schemas) => {
+  schema = withoutNull(schema) || {};
+  if (schema.$ref) {
+    return schemas[schema.$ref.split("/").pop()] || {};
+  }
+  return schema;
+};
+
+const isFile = (schema) =>
+  schema.type === "string" && schema.format === "binary";
+
+// Objects and arrays are sent as json, anything else as text.
+const defaultContentType = (schema, file) => {
+  if (file) {
+    return "application/octet-stream";
+  }
+  return ["object", "array"].includes(schema.type) ||
+    schema.properties ||
+    schema.allOf
+    ? "application/json"
+    : "text/plain";
+};
+
+// The fields of the form struct generated for a multipart/form-data body, one
+// per property of its schema. Binary properties become files, and the items of
+// array properties are sent as parts of the same name.
+const getMultipartFields = (operation, schemas, is_cabi_str = "false") => {
+  schemas = schemas || {};
+  const media = operation.requestBody.content["multipart/form-data"];
+  const schema = mergeAllOf(resolve(media.schema, schemas), schemas);
+  const properties = schema.properties || {};
+  const required = schema.required || [];
+  const encoding = media.encoding || {};
+  return Object.entries(properties).map(([name, prop]) => {
+    const resolved = resolve(prop, schemas);
+    const array = resolved.type === "array";
+    const item = array ? resolve(resolved.items, schemas) : resolved;
+    const file = isFile(item);
+    const contentType =
+      encoding[name] && encoding[name].contentType
+        ? encoding[name].contentType.split(",")[0].trim()
+        : defaultContentType(item, file);
+    const is_required = required.includes(name);
+    let type;
+    if (file) {
+      type = array ? "Vec<FilePart>" : "FilePart";
+      type = is_required ? type : `Option<${type}>`;
+    } else {
+      type = typeConvert(prop, is_required, is_cabi_str === "true");
+    }
+    return {
+      name,
+      field: toRustFieldName(name, properties),
+      description: prop.description,
+      type,
+      // arrays are never optional, nullable values always are
+      optional: type.startsWith("Option<"),
+      array,
+      file,
+      contentType,
+    };
+  });
+};
+
+module.exports = getMultipartFields;
@@ -0,0 +1,14 @@
+// Token endpoint of an OAuth2 security scheme, taken from the first of its
+// flows which declares one. Undefined for other schemes.
+const getOAuth2TokenUrl = (scheme) => {
+  if (!scheme || scheme.type !== "oauth2") {
+    return undefined;
+  }
+  const flows = scheme.flows || {};
+  const flow = ["clientCredentials", "authorizationCode", "password"]
+    .map((name) => flows[name])
+    .find((flow) => flow && flow.tokenUrl);
+  return flow && flow.tokenUrl;
+};
+
+module.exports = getOAuth2TokenUrl;
@@ -0,0 +1,26 @@
+const fieldTypeConvert = require("./fieldTypeConvert");
+const isRequiredField = require("./isRequiredField");
+const toRustFieldName = require("./toRustFieldName");
+
+// The properties of an object parameter, with the field of the generated struct
+// which holds each and the number of Options it is wrapped in, e.g. two for an
+// optional nullable property.
+const getObjectParamFields = (schema, is_cabi_str = "false") => {
+  const properties = schema.properties || {};
+  return Object.entries(properties).map(([name, prop]) => {
+    const type = String(
+      fieldTypeConvert(
+        prop,
+        isRequiredField(schema.required, name, prop),
+        is_cabi_str
+      )
+    );
+    return {
+      name,
+      field: toRustFieldName(name, properties, schema),
+      options: /^(Option<)*/.exec(type)[0].length / "Option<".length,
+    };
+  });
+};
+
+module.exports = getObjectParamFields;
@@ -0,0 +1,190 @@
+const typeConvert = require("./typeConvert");
+const getMediaTypeKind = require("./getMediaTypeKind");
+const isBinarySchema = require("./isBinarySchema");
+const toResponseTypeName = require("./toResponseTypeName");
+
+const REASON_PHRASES = {
+  100: "Continue",
+  101: "SwitchingProtocols",
+  200: "Ok",
+  201: "Created",
+  202: "Accepted",
+  203: "NonAuthoritativeInformation",
+  204: "NoContent",
+  205: "ResetContent",
+  206: "PartialContent",
+  300: "MultipleChoices",
+  301: "MovedPermanently",
+  302: "Found",
+  303: "SeeOther",
+  304: "NotModified",
+  307: "TemporaryRedirect",
+  308: "PermanentRedirect",
+  400: "BadRequest",
+  401: "Unauthorized",
+  402: "PaymentRequired",
+  403: "Forbidden",
+  404: "NotFound",
+  405: "MethodNotAllowed",
+  406: "NotAcceptable",
+  407: "ProxyAuthenticationRequired",
+  408: "RequestTimeout",
+  409: "Conflict",
+  410: "Gone",
+  411: "LengthRequired",
+  412: "PreconditionFailed",
+  413: "PayloadTooLarge",
+  414: "UriTooLong",
+  415: "UnsupportedMediaType",
+  416: "RangeNotSatisfiable",
+  417: "ExpectationFailed",
+  418: "ImATeapot",
+  421: "MisdirectedRequest",
+  422: "UnprocessableEntity",
+  423: "Locked",
+  424: "FailedDependency",
+  425: "TooEarly",
+  426: "UpgradeRequired",
+  428: "PreconditionRequired",
+  429: "TooManyRequests",
+  431: "RequestHeaderFieldsTooLarge",
+  451: "UnavailableForLegalReasons",
+  500: "InternalServerError",
+  501: "NotImplemented",
+  502: "BadGateway",
+  503: "ServiceUnavailable",
+  504: "GatewayTimeout",
+  505: "HttpVersionNotSupported",
+  507: "InsufficientStorage",
+  511: "NetworkAuthenticationRequired",
+};
+
+const RANGE_NAMES = {
+  1: "Informational",
+  2: "Success",
+  3: "Redirection",
+  4: "ClientError",
+  5: "ServerError",
+};
+
+// The media type whose schema is decoded, json is preferred over text.
+const pickMediaType = (content) => {
+  const types = Object.keys(content || {});
+  return (
+    types.find((type) => getMediaTypeKind(type) === "json") ||
+    types.find((type) => getMediaTypeKind(type) === "text") ||
+    types[0]
+  );
+};
+
+// Scalars which are parsed with their FromStr impl when sent as text. The
+// boxed dates of the C ABI are not.
+const PARSED_TYPE =
+  /^(i32|i64|f32|f64|bool|rust_decimal::Decimal|crate::formats::\w+|chrono::naive::NaiveDate|chrono::DateTime<chrono::Utc>)$/;
+
+// How the body of a response is read: strings as text, scalars as text or
+// json and everything else as json, unless it is binary. Media types which are
+// neither json nor text are read as bytes, the C ABI reads them as text.
+const decodeOf = (schema, type, mediaType, is_cabi_str) => {
+  if (isBinarySchema(schema, is_cabi_str)) {
+    return "bytes";
+  }
+  if (type === "()") {
+    return "none";
+  }
+  const kind = getMediaTypeKind(mediaType);
+  if (kind !== "json" && kind !== "text" && is_cabi_str !== "true") {
+    return "bytes";
+  }
+  if (type === "String") {
+    return "text";
+  }
+  return PARSED_TYPE.test(type) ? "parse" : "json";
+};
+
+const variantName = (status) => {
+  if (status === "default") {
+    return "Default";
+  }
+  const range = /^([1-5])XX$/i.exec(status);
+  if (range) {
+    return `${RANGE_NAMES[range[1]]}${range[1]}XX`;
+  }
+  return `${REASON_PHRASES[status] || "Status"}${status}`;
+};
+
+// The variant of the response openapi-forge picked as `_response`, which is
+// the one the C ABI functions return.
+const findPrimary = (operation, variants) => {
+  const schema = operation._response && operation._response.schema;
+  if (schema !== undefined) {
+    return variants.find((variant) => variant.schema === schema);
+  }
+  return (
+    variants.find((variant) => !variant.type && /^2/.test(variant.status)) ||
+    variants.find((variant) => !variant.type && variant.status === "default")
+  );
+};
+
+// Returns one variant per documented response of an operation, exact statuses
+// first, then status ranges and the default response, which is the order
+// they are matched in. Variants of ranges and of the default response also
+// hold the actual status code. An operation which documents no response gets
+// a default one without a body, one without a default response gets an
+// `Other` variant for the raw body of any other status.
+const getResponseVariants = (operation, is_cabi_str = "false") => {
+  const is_cabi = is_cabi_str === "true";
+  const responses = Object.keys(operation.responses || {}).length
+    ? operation.responses
+    : { default: {} };
+  const order = (status) =>
+    status === "default" ? 2 : /^[1-5]XX$/i.test(status) ? 1 : 0;
+  const variants = Object.keys(responses)
+    .sort((a, b) => order(a) - order(b))
+    .map((status) => {
+      const content = responses[status].content;
+      const mediaType = pickMediaType(content);
+      const schema = mediaType && content[mediaType].schema;
+      const typeName = toResponseTypeName(operation, status);
+      const type = schema && typeConvert(schema, true, is_cabi, typeName);
+      const decode = schema
+        ? decodeOf(schema, type, mediaType, is_cabi_str)
+        : "none";
+      const range = /^([1-5])XX$/i.exec(status);
+      return {
+        name: variantName(status),
+        status,
+        description: responses[status].description,
+        schema,
+        pattern: range
+          ? `${range[1]}00..=${range[1]}99`
+          : status === "default"
+          ? "_"
+          : status,
+        has_status: order(status) > 0,
+        decode,
+        media_type: mediaType,
+        type:
+          decode === "none" ? null : decode === "bytes" ? "bytes::Bytes" : type,
+      };
+    });
+  if (!responses.default) {
+    variants.push({
+      name: "Other",
+      description:
+        "A status which no response documents, left undecoded as the configuration does not treat it as an error.",
+      pattern: "_",
+      other: true,
+      has_status: true,
+      decode: "bytes",
+      type: "bytes::Bytes",
+    });
+  }
+  const primary = findPrimary(operation, variants);
+  if (primary) {
+    primary.primary = true;
+  }
+  return variants;
+};
+
+module.exports = getResponseVariants;
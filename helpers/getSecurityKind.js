@@ -0,0 +1,24 @@
+// How the credentials of a security scheme are sent, undefined for schemes
+// which are not supported, e.g. HTTP digest or mutual TLS.
+const getSecurityKind = (scheme) => {
+  if (!scheme || typeof scheme !== "object") {
+    return undefined;
+  }
+  switch (scheme.type) {
+    case "http": {
+      const name = String(scheme.scheme || "").toLowerCase();
+      return name === "bearer" || name === "basic" ? name : undefined;
+    }
+    case "apiKey":
+      return ["header", "query", "cookie"].includes(scheme.in)
+        ? "apiKey"
+        : undefined;
+    case "oauth2":
+    case "openIdConnect":
+      return "bearer";
+    default:
+      return undefined;
+  }
+};
+
+module.exports = getSecurityKind;
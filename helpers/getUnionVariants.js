@@ -0,0 +1,69 @@
+const typeConvert = require("./typeConvert");
+const toClassName = require("./toClassName");
+const isTaggedUnion = require("./isTaggedUnion");
+const toInlineTypeName = require("./toInlineTypeName");
+const isRecursiveField = require("./isRecursiveField");
+const toBoxedType = require("./toBoxedType");
+
+const refName = (ref) => ref.split("/").pop();
+
+const discriminatorValue = (member, discriminator) => {
+  const name = refName(member.$ref);
+  if (discriminator.mapping) {
+    for (const [value, target] of Object.entries(discriminator.mapping)) {
+      if (target === member.$ref || refName(target) === name) {
+        return value;
+      }
+    }
+  }
+  return name;
+};
+
+// e.g. `Vec<chrono::naive::NaiveDate>` becomes `VecNaiveDate`
+const toVariantName = (type) => {
+  const name = type
+    .replace(/\w+::/g, "")
+    .split(/[^a-z0-9]+/i)
+    .map(toClassName)
+    .join("");
+  return name === "" ? "Unit" : name;
+};
+
+// Returns the enum variants of a oneOf / anyOf schema as { name, type, tag } objects,
+// where tag is the discriminator value when the union is internally tagged.
+// Variants which hold the union itself are boxed.
+const getUnionVariants = (
+  schema,
+  is_cabi_str = "false",
+  union_name = "",
+  schemas = {}
+) => {
+  const is_cabi = is_cabi_str === "true";
+  const tagged = isTaggedUnion(schema);
+  const usedNames = new Set();
+  return (schema.oneOf || schema.anyOf).map((member, index) => {
+    let type = typeConvert(
+      member,
+      true,
+      is_cabi,
+      toInlineTypeName(union_name, `variant ${index + 1}`)
+    );
+    let name = member.$ref
+      ? toClassName(refName(member.$ref))
+      : toVariantName(type);
+    if (usedNames.has(name)) {
+      name += index + 1;
+    }
+    usedNames.add(name);
+    if (isRecursiveField(union_name, `variant ${index + 1}`, member, schemas)) {
+      type = toBoxedType(type);
+    }
+    return {
+      name,
+      type,
+      tag: tagged ? discriminatorValue(member, schema.discriminator) : null,
+    };
+  });
+};
+
+module.exports = getUnionVariants;
@@ -0,0 +1,9 @@
+// Binary bodies are passed around as bytes::Bytes, rather than decoded as json
+// or text. The C ABI keeps them as strings.
+const isBinarySchema = (schema, is_cabi_str = "false") =>
+  is_cabi_str !== "true" &&
+  !!schema &&
+  schema.type === "string" &&
+  schema.format === "binary";
+
+module.exports = isBinarySchema;
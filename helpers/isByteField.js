@@ -0,0 +1,13 @@
+const withoutNull = require("./withoutNull");
+
+// Base64 encoded fields are decoded into a Vec<u8>, the C ABI keeps them as
+// strings. Only fields themselves are decoded, nested values stay strings.
+const isByteField = (prop, is_cabi_str = "false") => {
+  if (is_cabi_str === "true" || !prop || typeof prop !== "object") {
+    return false;
+  }
+  const schema = withoutNull(prop);
+  return schema.type === "string" && schema.format === "byte";
+};
+
+module.exports = isByteField;
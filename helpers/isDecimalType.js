@@ -0,0 +1,6 @@
+// Whether a field of this type holds decimals, possibly wrapped in Options and
+// Vecs, which are serialized as json numbers.
+const isDecimalType = (type) =>
+  /^((Option|Vec)<)*rust_decimal::Decimal>*$/.test(String(type));
+
+module.exports = isDecimalType;
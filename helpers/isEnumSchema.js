@@ -0,0 +1,23 @@
+const withoutNull = require("./withoutNull");
+
+// Only string and integer enums are generated as Rust enums, other enums keep
+// their primitive type.
+const isEnumSchema = (schema) => {
+  if (!schema || !Array.isArray(schema.enum)) {
+    return false;
+  }
+  schema = withoutNull(schema);
+  const values = schema.enum.filter((value) => value !== null);
+  switch (schema.type) {
+    case "string":
+      return values.every((value) => typeof value === "string");
+    case "integer":
+      return values.every((value) => Number.isInteger(value));
+    case undefined:
+      return values.every((value) => typeof value === "string");
+    default:
+      return false;
+  }
+};
+
+module.exports = isEnumSchema;
@@ -0,0 +1,8 @@
+// Whether requests of the HTTP method can be repeated without further effect,
+// which makes them safe to retry.
+const isIdempotentMethod = (method) =>
+  ["get", "head", "put", "delete", "options"].includes(
+    String(method).toLowerCase()
+  );
+
+module.exports = isIdempotentMethod;
@@ -0,0 +1,18 @@
+// Covers `nullable: true` (3.0), `type: [T, "null"]` (3.1) and unions with a
+// `type: "null"` member.
+const isNullable = (schema) => {
+  if (!schema || typeof schema !== "object") {
+    return false;
+  }
+  return (
+    schema.nullable === true ||
+    (Array.isArray(schema.type) && schema.type.includes("null")) ||
+    [schema.oneOf, schema.anyOf].some(
+      (members) =>
+        Array.isArray(members) &&
+        members.some((member) => member.type === "null")
+    )
+  );
+};
+
+module.exports = isNullable;
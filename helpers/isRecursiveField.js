@@ -0,0 +1,146 @@
+const isEnumSchema = require("./isEnumSchema");
+const mergeAllOf = require("./mergeAllOf");
+const needsNamedType = require("./needsNamedType");
+const toInlineTypeName = require("./toInlineTypeName");
+const withoutNull = require("./withoutNull");
+
+const graphs = new WeakMap();
+
+const refName = (ref) => ref.split("/").pop();
+
+// The model generated for a value of `schema` which is not behind a pointer,
+// along with the schema of that model when it is an inline one.
+const valueTarget = (schema, typeName) => {
+  if (!schema || typeof schema !== "object") {
+    return undefined;
+  }
+  schema = withoutNull(schema);
+  if (schema.$ref) {
+    return { name: refName(schema.$ref) };
+  }
+  if (typeName && needsNamedType(schema)) {
+    return { name: typeName, schema };
+  }
+  const members = schema.oneOf || schema.anyOf || schema.allOf;
+  if (members && members.length === 1) {
+    return valueTarget(members[0], typeName);
+  }
+  return undefined;
+};
+
+// Maps every generated model, inline ones included, onto the models it holds by
+// value. Vec and HashMap already put their elements behind a pointer, so only
+// the named types found through them are followed.
+const buildGraph = (schemas) => {
+  const graph = new Map();
+  const pending = Object.entries(schemas);
+
+  const visitValue = (parent, schema, typeName, byValue) => {
+    if (!schema || typeof schema !== "object") {
+      return;
+    }
+    schema = withoutNull(schema);
+    const target = valueTarget(schema, typeName);
+    if (target !== undefined) {
+      if (target.schema) {
+        pending.push([target.name, target.schema]);
+      }
+      if (byValue) {
+        graph.get(parent).add(target.name);
+      }
+      return;
+    }
+    if (schema.type === "array") {
+      visitValue(
+        parent,
+        schema.items,
+        typeName && toInlineTypeName(typeName, "item"),
+        false
+      );
+    } else if (typeof schema.additionalProperties === "object") {
+      visitValue(
+        parent,
+        schema.additionalProperties,
+        typeName && toInlineTypeName(typeName, "value"),
+        false
+      );
+    }
+  };
+
+  while (pending.length > 0) {
+    const [name, nullableSchema] = pending.pop();
+    if (
+      graph.has(name) ||
+      !nullableSchema ||
+      typeof nullableSchema !== "object"
+    ) {
+      continue;
+    }
+    const schema = withoutNull(nullableSchema);
+    graph.set(name, new Set());
+    const members = schema.oneOf || schema.anyOf;
+    if (members) {
+      members.forEach((member, index) =>
+        visitValue(
+          name,
+          member,
+          toInlineTypeName(name, `variant ${index + 1}`),
+          true
+        )
+      );
+    } else if (!isEnumSchema(schema)) {
+      const merged = mergeAllOf(schema, schemas);
+      for (const flattened of merged._flattened || []) {
+        visitValue(name, flattened.schema, undefined, true);
+      }
+      for (const [key, prop] of Object.entries(merged.properties || {})) {
+        visitValue(name, prop, toInlineTypeName(name, key), true);
+      }
+      if (typeof merged.additionalProperties === "object") {
+        visitValue(
+          name,
+          { type: "object", additionalProperties: merged.additionalProperties },
+          name,
+          false
+        );
+      }
+    }
+  }
+  return graph;
+};
+
+const reaches = (graph, from, to) => {
+  const visited = new Set();
+  const stack = [from];
+  while (stack.length > 0) {
+    const name = stack.pop();
+    if (name === to) {
+      return true;
+    }
+    if (!visited.has(name)) {
+      visited.add(name);
+      stack.push(...(graph.get(name) || []));
+    }
+  }
+  return false;
+};
+
+// Whether the `key` field of the `parent` model holds, by value, a model which
+// in turn holds `parent` by value. Such fields are boxed, as the types would
+// otherwise have an infinite size.
+const isRecursiveField = (parent, key, prop, schemas) => {
+  if (!schemas || typeof schemas !== "object") {
+    return false;
+  }
+  if (!graphs.has(schemas)) {
+    graphs.set(schemas, buildGraph(schemas));
+  }
+  const graph = graphs.get(schemas);
+  if (!graph.has(parent)) {
+    return false;
+  }
+  const target = valueTarget(prop, toInlineTypeName(parent, key));
+  return target !== undefined && reaches(graph, target.name, parent);
+};
+
+module.exports = isRecursiveField;
@@ -0,0 +1,8 @@
+// readOnly fields are absent from requests and writeOnly fields from responses,
+// so the struct shared by both cannot require either of them.
+const isRequiredField = (required, key, prop) =>
+  Array.isArray(required) &&
+  required.includes(key) &&
+  !(prop && (prop.readOnly === true || prop.writeOnly === true));
+
+module.exports = isRequiredField;
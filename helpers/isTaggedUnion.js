@@ -0,0 +1,11 @@
+// A oneOf / anyOf schema can only be mapped onto an internally tagged serde enum
+// when it has a discriminator and every member is a reference to a named schema.
+const isTaggedUnion = (schema) => {
+  if (!schema || !schema.discriminator || !schema.discriminator.propertyName) {
+    return false;
+  }
+  const members = schema.oneOf || schema.anyOf;
+  return Array.isArray(members) && members.every((member) => member.$ref);
+};
+
+module.exports = isTaggedUnion;
@@ -0,0 +1,50 @@
+const toClassName = require("./toClassName");
+const toParamName = require("./toParamName");
+const toRustParamName = require("./toRustParamName");
+
+const mergeInto = (merged, schema, schemas, visited) => {
+  for (const member of schema.allOf || []) {
+    if (member.$ref) {
+      const name = member.$ref.split("/").pop();
+      const target = schemas[name];
+      if (target === undefined || visited.has(name)) {
+        continue;
+      }
+      if (target.oneOf || target.anyOf) {
+        // unions cannot be merged field by field, embed them instead
+        merged._flattened.push({
+          name: toRustParamName(toParamName(name)),
+          type: toClassName(name),
+          schema: member,
+        });
+        continue;
+      }
+      visited.add(name);
+      mergeInto(merged, target, schemas, visited);
+    } else {
+      mergeInto(merged, member, schemas, visited);
+    }
+  }
+  Object.assign(merged.properties, schema.properties);
+  merged.required.push(...(schema.required || []));
+};
+
+// Flattens the allOf members of a schema, so that the properties and the
+// required list of every referenced schema end up on the generated struct.
+const mergeAllOf = (schema, schemas) => {
+  if (!schema.allOf) {
+    return schema;
+  }
+  if (!schemas || typeof schemas !== "object") {
+    schemas = {};
+  }
+  const merged = Object.assign({}, schema, {
+    properties: {},
+    required: [],
+    _flattened: [],
+  });
+  mergeInto(merged, schema, schemas, new Set());
+  return merged;
+};
+
+module.exports = mergeAllOf;
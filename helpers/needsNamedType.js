@@ -0,0 +1,22 @@
+const isEnumSchema = require("./isEnumSchema");
+
+// Inline schemas which typeConvert maps onto a generated type of their own,
+// rather than onto a Rust primitive or collection.
+const needsNamedType = (schema) => {
+  if (!schema || typeof schema !== "object" || schema.$ref) {
+    return false;
+  }
+  if (isEnumSchema(schema)) {
+    return true;
+  }
+  const members = schema.oneOf || schema.anyOf || schema.allOf;
+  if (members) {
+    return members.length > 1;
+  }
+  return (
+    (schema.type === "object" || schema.type === undefined) &&
+    schema.properties !== undefined
+  );
+};
+
+module.exports = needsNamedType;
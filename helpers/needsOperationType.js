@@ -0,0 +1,15 @@
+const isEnumSchema = require("./isEnumSchema");
+const needsNamedType = require("./needsNamedType");
+const withoutNull = require("./withoutNull");
+
+// Inline parameter and response schemas only get a generated type when they
+// describe objects, enums are kept as plain strings and integers.
+const needsOperationType = (schema) => {
+  schema = withoutNull(schema);
+  while (schema && schema.type === "array") {
+    schema = withoutNull(schema.items);
+  }
+  return needsNamedType(schema) && !isEnumSchema(schema);
+};
+
+module.exports = needsOperationType;
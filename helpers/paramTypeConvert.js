@@ -0,0 +1,27 @@
+const Handlebars = require("handlebars");
+const getBodyMediaType = require("./getBodyMediaType");
+const safeTypeConvert = require("./safeTypeConvert");
+const toClassName = require("./toClassName");
+const toParamTypeName = require("./toParamTypeName");
+
+// Rust type of an operation parameter, multipart/form-data bodies are passed as
+// the form struct generated for the operation.
+const paramTypeConvert = (param, operation, is_cabi_str = "false") => {
+  if (
+    param.in === "body" &&
+    getBodyMediaType(operation) === "multipart/form-data"
+  ) {
+    const type = toClassName(operation.operationId) + "Form";
+    return new Handlebars.SafeString(
+      param.required === true ? type : `Option<${type}>`
+    );
+  }
+  return safeTypeConvert(
+    param.schema,
+    param.required === true,
+    is_cabi_str,
+    toParamTypeName(operation.operationId, param)
+  );
+};
+
+module.exports = paramTypeConvert;
@@ -1,48 +1,8 @@
-const supportedTypes = ["application/json", "text/plain"];
-const arrayCount = supportedTypes.length - 1;
+const getBodyMediaTypes = require("./getBodyMediaTypes");
 
-const pathContentTypeSupported = (path) => {
-  if (path.requestBody) {
-    if (path.requestBody.content) {
-      for (let i = 0; i <= arrayCount; i++) {
-        if (path.requestBody.content[supportedTypes[i]]) {
-          return true;
-        }
-      }
-    }
-    return false;
-  }
-
-  if (path.responses) {
-    for (let i = 0; i <= arrayCount; i++) {
-      if (
-        Object.entries(path.responses).some(
-          (entry) =>
-            entry[1].content !== undefined &&
-            entry[1].content[supportedTypes[i]]
-        )
-      ) {
-        return true;
-      }
-
-      if (
-        Object.entries(path.responses).every(
-          (entry) => entry[1].content === undefined
-        )
-      ) {
-        return true;
-      }
-
-      if (
-        Object.entries(path.responses).length === 1 &&
-        Object.entries(path.responses)[0][1].default !== undefined
-      ) {
-        return true;
-      }
-    }
-    return false;
-  }
-  return true;
-};
+// Whether an operation can be sent, which it can unless none of the media types
+// of its request body is supported. Responses of any media type are decoded.
+const pathContentTypeSupported = (path, is_cabi_str = "false") =>
+  !path.requestBody || getBodyMediaTypes(path, is_cabi_str).length > 0;
 
 module.exports = pathContentTypeSupported;
@@ -1,12 +1,22 @@
 const Handlebars = require("handlebars");
 const typeConvert = require("./typeConvert");
 
-const safeTypeConvert = (prop, is_required = true, is_cabi_str = "false") => {
+const safeTypeConvert = (
+  prop,
+  is_required = true,
+  is_cabi_str = "false",
+  type_name = undefined
+) => {
   if (typeof is_required !== "boolean") {
     is_required = true;
   }
+  if (typeof type_name !== "string") {
+    type_name = undefined;
+  }
   const is_cabi = is_cabi_str === "true";
-  return new Handlebars.SafeString(typeConvert(prop, is_required, is_cabi));
+  return new Handlebars.SafeString(
+    typeConvert(prop, is_required, is_cabi, type_name)
+  );
 };
 
 module.exports = safeTypeConvert;
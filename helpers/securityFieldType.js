@@ -0,0 +1,17 @@
+const getSecurityKind = require("./getSecurityKind");
+
+// Type of the Configuration field holding the credentials of a security scheme,
+// undefined for schemes which are not supported.
+const securityFieldType = (scheme) => {
+  switch (getSecurityKind(scheme)) {
+    case "basic":
+      return "BasicCredentials";
+    case "bearer":
+    case "apiKey":
+      return "String";
+    default:
+      return undefined;
+  }
+};
+
+module.exports = securityFieldType;
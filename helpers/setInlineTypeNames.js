@@ -0,0 +1,10 @@
+const getInlineSchemas = require("./getInlineSchemas");
+
+// Names the inline schemas up front, so that types are named the same way
+// wherever they are referred to, including before model.rs lists them.
+const setInlineTypeNames = (schemas, inlineObjects, paths) => {
+  getInlineSchemas(schemas, inlineObjects, paths);
+  return "";
+};
+
+module.exports = setInlineTypeNames;
@@ -1,5 +1,6 @@
 const Handlebars = require("handlebars");
 const toRustParamName = require("./toRustParamName");
+const toRustFieldName = require("./toRustFieldName");
 const getParametersByType = require("./getParametersByType");
 const getSome = require("./getSome");
 
@@ -30,8 +31,9 @@ const setPathParameters = (path, sortedParams, is_cabi = false) => {
             pathParam.schema.properties
           )) {
             // inside inline objects, there are no required annotations.
-            serialisedObject += `", &${safeParamName}.${toRustParamName(
-              propName
+            serialisedObject += `", &${safeParamName}.${toRustFieldName(
+              propName,
+              pathParam.schema.properties
             )}.map_or("".into(), |value| format!("${propName}${URL_SAFE_COMMA}{}", value)), "${URL_SAFE_COMMA}`;
           }
           return serialisedObject.slice(0, -3);
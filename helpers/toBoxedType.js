@@ -0,0 +1,14 @@
+// Boxes the type wrapped by any Options, e.g. `Option<Tree>` becomes
+// `Option<Box<Tree>>`.
+const toBoxedType = (type) => {
+  const type_str = String(type);
+  const match = /^(?:Option<)*/.exec(type_str)[0];
+  const depth = match.length / "Option<".length;
+  return (
+    match +
+    `Box<${type_str.slice(match.length, type_str.length - depth)}>` +
+    ">".repeat(depth)
+  );
+};
+
+module.exports = toBoxedType;
@@ -0,0 +1,25 @@
+const toPascalCase = (name) =>
+  String(name)
+    .split(/[^a-z0-9]+/i)
+    .map((part) => part.charAt(0).toUpperCase() + part.substr(1))
+    .join("");
+
+// Names which getInlineSchemas picked instead, as the natural one was taken.
+const renamed = new Map();
+
+const key = (parentName, propName) => JSON.stringify([parentName, propName]);
+
+// Name of the type generated for an inline schema, e.g. the `status`
+// property of `Pet` becomes `PetStatus`, unless a component schema or another
+// inline schema has that name already.
+const toInlineTypeName = (parentName, propName) =>
+  renamed.get(key(parentName, propName)) ||
+  toPascalCase(parentName) + toPascalCase(propName);
+
+toInlineTypeName.rename = (parentName, propName, name) => {
+  renamed.set(key(parentName, propName), name);
+};
+
+toInlineTypeName.clearRenames = () => renamed.clear();
+
+module.exports = toInlineTypeName;
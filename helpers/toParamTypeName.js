@@ -0,0 +1,13 @@
+const needsOperationType = require("./needsOperationType");
+const toInlineTypeName = require("./toInlineTypeName");
+
+// Name of the type generated for an inline parameter schema, e.g. the `filter`
+// parameter of `findPets` becomes `FindPetsFilter`.
+const toParamTypeName = (operationId, param) => {
+  if (!needsOperationType(param.schema)) {
+    return undefined;
+  }
+  return toInlineTypeName(operationId, param.name);
+};
+
+module.exports = toParamTypeName;
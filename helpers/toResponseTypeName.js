@@ -0,0 +1,31 @@
+const needsOperationType = require("./needsOperationType");
+const toInlineTypeName = require("./toInlineTypeName");
+
+const findStatus = (responses, schema) =>
+  Object.keys(responses).find((code) =>
+    Object.values(responses[code].content || {}).some(
+      (media) => media.schema === schema
+    )
+  );
+
+// Name of the type generated for an inline response schema, e.g. the 200
+// response of `getPet` becomes `GetPet200Response`. Without a status, the
+// response openapi-forge picked as `_response` is named.
+const toResponseTypeName = (operation, status) => {
+  const responses = operation.responses || {};
+  let schema;
+  if (typeof status === "string") {
+    schema = Object.values((responses[status] || {}).content || {})
+      .map((media) => media.schema)
+      .find((schema) => schema !== undefined);
+  } else {
+    schema = operation._response && operation._response.schema;
+    status = findStatus(responses, schema);
+  }
+  if (!needsOperationType(schema)) {
+    return undefined;
+  }
+  return toInlineTypeName(operation.operationId, `${status || ""} response`);
+};
+
+module.exports = toResponseTypeName;
@@ -0,0 +1,112 @@
+// Keywords which can be used as raw identifiers, e.g. `r#type`.
+const rawKeywords = new Set([
+  "abstract",
+  "as",
+  "async",
+  "await",
+  "become",
+  "box",
+  "break",
+  "const",
+  "continue",
+  "do",
+  "dyn",
+  "else",
+  "enum",
+  "extern",
+  "false",
+  "final",
+  "fn",
+  "for",
+  "if",
+  "impl",
+  "in",
+  "let",
+  "loop",
+  "macro",
+  "match",
+  "mod",
+  "move",
+  "mut",
+  "override",
+  "priv",
+  "pub",
+  "ref",
+  "return",
+  "static",
+  "struct",
+  "trait",
+  "true",
+  "try",
+  "type",
+  "typeof",
+  "unsafe",
+  "unsized",
+  "use",
+  "virtual",
+  "where",
+  "while",
+  "yield",
+]);
+
+// Keywords which cannot be raw identifiers, these get an underscore suffix.
+const reservedNames = new Set(["crate", "self", "super"]);
+
+// e.g. `HTTPStatus` becomes `http_status` and `x-rate-limit` becomes `x_rate_limit`
+const toSnakeCase = (name) =>
+  name
+    .replace(/([A-Z]+)([A-Z][a-z])/g, "$1_$2")
+    .replace(/([a-z])([A-Z])/g, "$1_$2")
+    .replace(/([a-zA-Z])([0-9])/g, "$1_$2")
+    .replace(/([0-9])([a-zA-Z])/g, "$1_$2")
+    .replace(/[^a-zA-Z0-9]+/g, "_")
+    .replace(/^_+|_+$/g, "")
+    .toLowerCase();
+
+const toIdentifier = (key) => {
+  const name = toSnakeCase(String(key));
+  if (name === "") {
+    return "field";
+  }
+  return /^[0-9]/.test(name) ? "_" + name : name;
+};
+
+const escapeKeyword = (name) => {
+  if (rawKeywords.has(name)) {
+    return "r#" + name;
+  }
+  return reservedNames.has(name) ? name + "_" : name;
+};
+
+// Names of the fields a struct gets besides those of its properties, i.e. the
+// flattened allOf unions and the additional properties.
+const generatedNames = (schema) => {
+  const names = ((schema && schema._flattened) || []).map((flattened) =>
+    flattened.name.replace(/^r#/, "")
+  );
+  if (schema && schema.additionalProperties) {
+    names.push("additional_properties");
+  }
+  return names;
+};
+
+// Rust identifier of the field generated for the property `key`, which is
+// unique among the fields of its struct. Clashing names get a numeric
+// suffix in declaration order.
+const toRustFieldName = (key, properties, schema) => {
+  const usedNames = new Set(generatedNames(schema));
+  for (const propName of Object.keys(properties || {})) {
+    const name = toIdentifier(propName);
+    let uniqueName = name;
+    for (let suffix = 2; usedNames.has(uniqueName); suffix++) {
+      uniqueName = `${name}_${suffix}`;
+    }
+    usedNames.add(uniqueName);
+    if (propName === key) {
+      return escapeKeyword(uniqueName);
+    }
+  }
+  return escapeKeyword(toIdentifier(key));
+};
+
+module.exports = toRustFieldName;
@@ -0,0 +1,29 @@
+const Handlebars = require("handlebars");
+
+const ESCAPES = {
+  '"': '\\"',
+  "\\": "\\\\",
+  "\n": "\\n",
+  "\r": "\\r",
+  "\t": "\\t",
+  "\0": "\\0",
+};
+
+// A Rust string literal holding the value, other control characters are
+// written as `\u{..}` escapes.
+const toRustString = (value) => {
+  let literal = "";
+  for (const char of String(value)) {
+    const code = char.codePointAt(0);
+    if (ESCAPES[char]) {
+      literal += ESCAPES[char];
+    } else if (code < 0x20 || code === 0x7f) {
+      literal += `\\u{${code.toString(16)}}`;
+    } else {
+      literal += char;
+    }
+  }
+  return new Handlebars.SafeString(`"${literal}"`);
+};
+
+module.exports = toRustString;
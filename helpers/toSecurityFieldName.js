@@ -0,0 +1,23 @@
+const toRustFieldName = require("./toRustFieldName");
+
+// Fields which Configuration declares besides the security schemes.
+const configFields = new Set([
+  "base_path",
+  "bearer_token",
+  "selected_server_index",
+  "validate_requests",
+  "error_for_status",
+  "credential_provider",
+  "middleware",
+  "retry",
+  "request_options",
+]);
+
+// Name of the Configuration field holding the credentials of a security
+// scheme, e.g. `petstore_auth` for the `petstore_auth` scheme.
+const toSecurityFieldName = (name, schemes) => {
+  const field = toRustFieldName(name, schemes);
+  return configFields.has(field) ? `${field}_auth` : field;
+};
+
+module.exports = toSecurityFieldName;
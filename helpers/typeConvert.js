@@ -1,5 +1,30 @@
 const toSafeName = require("./toClassName");
+const toInlineTypeName = require("./toInlineTypeName");
+const isNullable = require("./isNullable");
+const needsNamedType = require("./needsNamedType");
+const withoutNull = require("./withoutNull");
+const withDecimalNumbers = require("./withDecimalNumbers");
 
+const DECIMAL = "rust_decimal::Decimal";
+
+// Decimals keep the cents of large monetary values.
+const numberType = () => (withDecimalNumbers.enabled ? DECIMAL : "f64");
+
+// Most format types live in the generated formats module, each falling back to
+// a String when its Cargo feature is disabled. The C ABI keeps them as strings.
+const FORMAT_TYPES = {
+  binary: "bytes::Bytes",
+  uuid: "crate::formats::Uuid",
+  uri: "crate::formats::Url",
+  url: "crate::formats::Url",
+  ipv4: "crate::formats::Ipv4Addr",
+  ipv6: "crate::formats::Ipv6Addr",
+  password: "crate::formats::Secret",
+  duration: "crate::formats::Duration",
+};
+
+// Returns undefined for formats without a dedicated type, which are then
+// converted by their type alone.
 const fromFormat = (propFormat, is_required, is_cabi) => {
   const naiveDate =
     (is_cabi ? "Box<" : "") + "chrono::naive::NaiveDate" + (is_cabi ? ">" : "");
@@ -7,6 +32,10 @@ const fromFormat = (propFormat, is_required, is_cabi) => {
     (is_cabi ? "Box<" : "") +
     "chrono::DateTime<chrono::Utc>" +
     (is_cabi ? ">" : "");
+  const type = is_cabi ? undefined : FORMAT_TYPES[propFormat];
+  if (type) {
+    return is_required ? type : `Option<${type}>`;
+  }
   switch (propFormat) {
     case "int32":
       return is_required ? "i32" : "Option<i32>";
@@ -16,6 +45,8 @@ const fromFormat = (propFormat, is_required, is_cabi) => {
       return is_required ? "f32" : "Option<f32>";
     case "double":
       return is_required ? "f64" : "Option<f64>";
+    case "decimal":
+      return is_required ? DECIMAL : `Option<${DECIMAL}>`;
     case "date":
       return is_required ? naiveDate : `Option<${naiveDate}>`;
     case "date-time":
@@ -25,51 +56,104 @@ const fromFormat = (propFormat, is_required, is_cabi) => {
     case "string":
       return is_required ? "String" : "Option<String>";
     default:
-      return is_required ? "()" : "Option<()>";
+      return undefined;
   }
 };
 
-const fromType = (propType, additionalProperties, items, is_required) => {
+const fromType = (
+  propType,
+  additionalProperties,
+  items,
+  is_required,
+  type_name
+) => {
   switch (propType) {
     case "integer":
       return is_required ? "i64" : "Option<i64>";
     case "number":
-      return is_required ? "f64" : "Option<f64>";
+      return is_required ? numberType() : `Option<${numberType()}>`;
     case "boolean":
       return is_required ? "bool" : "Option<bool>";
     case "string":
       return is_required ? "String" : "Option<String>";
     case "array":
-      return `Vec<${typeConvert(items, true)}>`;
+      return `Vec<${typeConvert(
+        items,
+        true,
+        false,
+        type_name && toInlineTypeName(type_name, "item")
+      )}>`;
     // inline object definition
     case "object":
       if (additionalProperties) {
-        return `HashMap<String,${typeConvert(
-          additionalProperties,
-          is_required
-        )}>`;
+        // `additionalProperties: true` allows values of any type
+        const valueType =
+          additionalProperties === true ||
+          Object.keys(additionalProperties).length === 0
+            ? "serde_json::Value"
+            : typeConvert(
+                additionalProperties,
+                true,
+                false,
+                type_name && toInlineTypeName(type_name, "value")
+              );
+        return is_required
+          ? `HashMap<String,${valueType}>`
+          : `Option<HashMap<String,${valueType}>>`;
       } else {
-        return "Object";
+        // free-form object, declared properties get a generated struct
+        return is_required ? "serde_json::Value" : "Option<serde_json::Value>";
       }
     default:
       return "";
   }
 };
 
-const typeConvert = (prop, is_required = true, is_cabi = false) => {
+// type_name is the name given to inline schemas which get a generated type
+const typeConvert = (
+  prop,
+  is_required = true,
+  is_cabi = false,
+  type_name = undefined
+) => {
   if (prop === null) return "()";
 
   if (prop === undefined) return "()";
 
+  // null is only ever represented by None, whether the value is required or not
+  if (isNullable(prop)) {
+    const type = typeConvert(withoutNull(prop), true, is_cabi, type_name);
+    return `Option<${type}>`;
+  }
+
   // resolve references
   if (prop.$ref) {
     const objectType = toSafeName(prop.$ref.split("/").pop());
     return is_required ? objectType : `Option<${objectType}>`;
   }
 
-  const type = prop.format
-    ? fromFormat(prop.format, is_required, is_cabi)
-    : fromType(prop.type, prop.additionalProperties, prop.items, is_required);
+  if (type_name && needsNamedType(prop)) {
+    return is_required ? type_name : `Option<${type_name}>`;
+  }
+
+  // compositions without a generated type
+  const members = prop.oneOf || prop.anyOf || prop.allOf;
+  if (members) {
+    if (members.length === 1) {
+      return typeConvert(members[0], is_required, is_cabi, type_name);
+    }
+    return is_required ? "serde_json::Value" : "Option<serde_json::Value>";
+  }
+
+  const type =
+    (prop.format && fromFormat(prop.format, is_required, is_cabi)) ||
+    fromType(
+      prop.type,
+      prop.additionalProperties,
+      prop.items,
+      is_required,
+      type_name
+    );
 
   return type === "" ? "()" : type;
 };
@@ -0,0 +1,18 @@
+// Whether the generated types hold any decimals: every number does with the
+// decimal_numbers option, otherwise only those of the decimal format.
+const usesDecimals = (spec, decimalNumbers) => {
+  if (decimalNumbers === "true") {
+    return true;
+  }
+  const seen = new Set();
+  const visit = (value) => {
+    if (value === null || typeof value !== "object" || seen.has(value)) {
+      return false;
+    }
+    seen.add(value);
+    return value.format === "decimal" || Object.values(value).some(visit);
+  };
+  return visit(spec);
+};
+
+module.exports = usesDecimals;
@@ -0,0 +1,16 @@
+// Renders the block with the decimal_numbers generator option in effect for
+// typeConvert, which is called from many helpers that have no access to the
+// options. It is restored afterwards, so that it never outlives the template.
+const withDecimalNumbers = function (value, options) {
+  const previous = withDecimalNumbers.enabled;
+  withDecimalNumbers.enabled = value === "true";
+  try {
+    return options.fn(this);
+  } finally {
+    withDecimalNumbers.enabled = previous;
+  }
+};
+
+withDecimalNumbers.enabled = false;
+
+module.exports = withDecimalNumbers;
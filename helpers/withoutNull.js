@@ -0,0 +1,25 @@
+const isNullable = require("./isNullable");
+
+// Returns the schema without its null alternative, e.g. `type: ["string", "null"]`
+// becomes `type: "string"`.
+const withoutNull = (schema) => {
+  if (!isNullable(schema)) {
+    return schema;
+  }
+  const nonNullable = Object.assign({}, schema);
+  delete nonNullable.nullable;
+  if (Array.isArray(nonNullable.type)) {
+    const types = nonNullable.type.filter((type) => type !== "null");
+    nonNullable.type = types.length === 1 ? types[0] : types;
+  }
+  for (const key of ["oneOf", "anyOf"]) {
+    if (Array.isArray(nonNullable[key])) {
+      nonNullable[key] = nonNullable[key].filter(
+        (member) => member.type !== "null"
+      );
+    }
+  }
+  return nonNullable;
+};
+
+module.exports = withoutNull;
@@ -0,0 +1,31 @@
+Feature: Binary responses
+
+  Scenario: a binary response of a media type other than application/octet-stream is returned
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets/photo": {
+            "get": {
+              "operationId": "getPhoto",
+              "responses": {
+                "200": {
+                  "description": "success",
+                  "content": {
+                    "image/png": {
+                      "schema": { "type": "string", "format": "binary" }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 200 and the image/png body "PNGDATA"
+    When calling the method getPhoto and recording the outcome
+    Then the outcome should be 'Ok200("PNGDATA")'
+    And the request should have a header property with value image/png
@@ -0,0 +1,55 @@
+Feature: Binary uploads
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets/photo": {
+            "put": {
+              "operationId": "putPhoto",
+              "requestBody": {
+                "content": {
+                  "image/png": {
+                    "schema": { "type": "string", "format": "binary" }
+                  }
+                }
+              },
+              "responses": { "204": { "description": "stored" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 204
+
+  Scenario: an optional binary body is sent as its media type
+    When calling the method putPhoto with parameters and recording the outcome
+      """
+      ["PNGDATA"]
+      """
+    Then the outcome should be "NoContent204"
+    And the last request should have the body "PNGDATA"
+    And the last request should have a header Content-Type with value "image/png"
+
+  Scenario: a request without its optional binary body has neither a body nor a Content-Type
+    When calling the method putPhoto with parameters and recording the outcome
+      """
+      [null]
+      """
+    Then the outcome should be "NoContent204"
+    And the last request should have the body ""
+    And the last request should not have a header Content-Type
+
+  Scenario: a request without its optional binary body has no Content-Type even when one was chosen
+    Given the configuration sends request bodies as "image/png"
+    When calling the method putPhoto with parameters and recording the outcome
+      """
+      [null]
+      """
+    Then the outcome should be "NoContent204"
+    And the server should have received 1 request
+    And the last request should have the body ""
+    And the last request should not have a header Content-Type
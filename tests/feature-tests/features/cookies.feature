@@ -0,0 +1,40 @@
+Feature: Cookies
+
+  Scenario: cookie parameters and cookie API keys are sent in a single Cookie header
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "security": [{ "session": [] }],
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "parameters": [
+                {
+                  "name": "theme",
+                  "in": "cookie",
+                  "required": true,
+                  "schema": { "type": "string" }
+                }
+              ],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        },
+        "components": {
+          "securitySchemes": {
+            "session": { "type": "apiKey", "in": "cookie", "name": "SESSION" }
+          }
+        }
+      }
+      """
+    And the session security scheme has the token abc
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      ["dark"]
+      """
+    Then the outcome should be "Ok200"
+    And the request should have a single cookie header with value "theme=dark;SESSION=abc"
@@ -0,0 +1,63 @@
+Feature: Credential providers
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "security": [{ "api_key": [] }],
+              "responses": { "200": { "description": "success" } }
+            }
+          },
+          "/owner": {
+            "get": {
+              "operationId": "getOwner",
+              "security": [{ "basic-auth": [] }],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        },
+        "components": {
+          "securitySchemes": {
+            "api_key": { "type": "apiKey", "in": "header", "name": "X-API-Key" },
+            "basic-auth": { "type": "http", "scheme": "basic" }
+          }
+        }
+      }
+      """
+    And the server responds with status 200
+
+  Scenario: an API key is read from the variable named after the scheme
+    Given the environment variable FORGE_KEY_API_KEY is "from-env"
+    And the configuration reads credentials from the environment with the prefix FORGE_KEY_
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the last request should have a header X-API-Key with value "from-env"
+
+  Scenario: basic credentials are read from the _USERNAME and _PASSWORD variables
+    Given the environment variable FORGE_BASIC_BASIC_AUTH_USERNAME is "tom"
+    And the environment variable FORGE_BASIC_BASIC_AUTH_PASSWORD is "secret"
+    And the configuration reads credentials from the environment with the prefix FORGE_BASIC_
+    When calling the method getOwner and recording the outcome
+    Then the outcome should be "Ok200"
+    And the last request should have a header Authorization with value "Basic dG9tOnNlY3JldA=="
+
+  Scenario: the provider wins over the configuration
+    Given the api_key security scheme has the token from-config
+    And the environment variable FORGE_WINS_API_KEY is "from-env"
+    And the configuration reads credentials from the environment with the prefix FORGE_WINS_
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the last request should have a header X-API-Key with value "from-env"
+
+  Scenario: the configuration is used when the provider has no credentials
+    Given the api_key security scheme has the token from-config
+    And the configuration reads credentials from the environment with the prefix FORGE_UNSET_
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the last request should have a header X-API-Key with value "from-config"
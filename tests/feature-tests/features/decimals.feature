@@ -0,0 +1,82 @@
+Feature: Decimal numbers
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/payments": {
+            "post": {
+              "operationId": "addPayment",
+              "requestBody": {
+                "required": true,
+                "content": {
+                  "application/json": {
+                    "schema": { "$ref": "#/components/schemas/Payment" }
+                  }
+                }
+              },
+              "responses": {
+                "201": {
+                  "description": "created",
+                  "content": {
+                    "application/json": {
+                      "schema": { "$ref": "#/components/schemas/Payment" }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Payment": {
+              "type": "object",
+              "required": ["amount"],
+              "properties": {
+                "amount": { "type": "number", "format": "decimal" }
+              }
+            }
+          }
+        }
+      }
+      """
+
+  Scenario: decimal fields are decimals
+    Then Payment should have a required property named amount of type rust_decimal::Decimal
+
+  Scenario: decimals keep every digit of the response
+    Given the server responds with status 201 and body
+      """
+      { "amount": 0.1000000000000000055511151231 }
+      """
+    When calling the method addPayment with parameters and recording the outcome
+      """
+      [{ "amount": "1" }]
+      """
+    Then the outcome should be "Created201(Payment { amount: 0.1000000000000000055511151231 })"
+
+  Scenario: decimals beyond the precision of a decimal are rounded rather than read as a float
+    Given the server responds with status 201 and body
+      """
+      { "amount": 0.1000000000000000055511151231257827 }
+      """
+    When calling the method addPayment with parameters and recording the outcome
+      """
+      [{ "amount": "1" }]
+      """
+    Then the outcome should be "Created201(Payment { amount: 0.1000000000000000055511151231 })"
+
+  Scenario: string encoded decimals are sent as numbers with every digit
+    Given the server responds with status 201 and body
+      """
+      { "amount": 1 }
+      """
+    When calling the method addPayment with parameters and recording the outcome
+      """
+      [{ "amount": "0.1000000000000000055511151231" }]
+      """
+    Then the request should have a body with value {"amount":0.1000000000000000055511151231}
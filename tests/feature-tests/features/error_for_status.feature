@@ -0,0 +1,79 @@
+Feature: Statuses which are not treated as errors
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pet": {
+            "get": {
+              "operationId": "getPet",
+              "responses": {
+                "200": { "description": "success" },
+                "404": { "description": "not found" }
+              }
+            }
+          },
+          "/owner": {
+            "get": {
+              "operationId": "getOwner",
+              "responses": {
+                "200": { "description": "success" },
+                "default": {
+                  "description": "anything else",
+                  "content": {
+                    "application/json": {
+                      "schema": { "$ref": "#/components/schemas/Problem" }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Problem": {
+              "type": "object",
+              "properties": { "message": { "type": "string" } }
+            }
+          }
+        }
+      }
+      """
+
+  Scenario: an undocumented status is an error by default
+    Given the server responds with status 500 and body
+      """
+      down
+      """
+    When calling the method getPet and recording the outcome
+    Then the outcome should contain "unexpected response status 500"
+
+  Scenario: an undocumented status is returned raw when statuses are not errors
+    Given the configuration does not treat statuses as errors
+    And the server responds with status 500 and body
+      """
+      down
+      """
+    When calling the method getPet and recording the outcome
+    Then the outcome should be 'Other(500, b"down")'
+
+  Scenario: an error status of the default response is an error by default
+    Given the server responds with status 500 and body
+      """
+      { "message": "down" }
+      """
+    When calling the method getOwner and recording the outcome
+    Then the outcome should contain "unexpected response status 500"
+
+  Scenario: an error status of the default response is decoded when statuses are not errors
+    Given the configuration does not treat statuses as errors
+    And the server responds with status 500 and body
+      """
+      { "message": "down" }
+      """
+    When calling the method getOwner and recording the outcome
+    Then the outcome should be 'Default(500, Problem { message: RSome("down") })'
@@ -0,0 +1,44 @@
+Feature: Form bodies
+
+  Scenario: the properties of a form body are sent in the order the schema declares them
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "post": {
+              "operationId": "createPet",
+              "requestBody": {
+                "required": true,
+                "content": {
+                  "application/x-www-form-urlencoded": {
+                    "schema": { "$ref": "#/components/schemas/Pet" }
+                  }
+                }
+              },
+              "responses": { "201": { "description": "created" } }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Pet": {
+              "type": "object",
+              "properties": {
+                "zeta": { "type": "string" },
+                "alpha": { "type": "string" }
+              }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 201
+    When calling the method createPet with parameters and recording the outcome
+      """
+      [{ "zeta": "z", "alpha": "a" }]
+      """
+    Then the outcome should be "Created201"
+    And the request should have a body with value zeta=z&alpha=a
@@ -0,0 +1,77 @@
+Feature: Middleware
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "responses": { "200": { "description": "success" } }
+            }
+          },
+          "/owner": {
+            "get": {
+              "operationId": "getOwner",
+              "security": [{ "oauth": [] }],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        },
+        "components": {
+          "securitySchemes": {
+            "oauth": {
+              "type": "oauth2",
+              "flows": {
+                "clientCredentials": {
+                  "tokenUrl": "http://127.0.0.1:8888/token",
+                  "scopes": {}
+                }
+              }
+            }
+          }
+        }
+      }
+      """
+
+  Scenario: requests go through the middleware in order and responses in reverse order
+    Given the configuration has the middleware first
+    And the configuration has the middleware second
+    And the server responds with status 200
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the last request should have the header X-Middleware with the values "first, second"
+    And the middleware should have recorded "request first, request second, response second, response first"
+
+  Scenario: the middleware runs again for every retry
+    Given the configuration has the middleware first
+    And the configuration retries 3 times with a backoff of 10 milliseconds
+    And the server responds with status 200
+    And the server first responds 1 time with status 503
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the last request should have the header X-Middleware with the values "first"
+    And the middleware should have recorded "request first, response first, request first, response first"
+
+  Scenario: the middleware runs again when a rejected request is sent with a renewed token
+    Given the configuration has the middleware first
+    And the oauth security scheme has the client credentials id and secret
+    And the server responds with status 200
+    And the token endpoint then issues the token first expiring in 3600 seconds
+    And the token endpoint then issues the token second expiring in 3600 seconds
+    And the server first responds 1 time with status 401
+    When calling the method getOwner and recording the outcome
+    Then the outcome should be "Ok200"
+    And the last request should have a header Authorization with value "Bearer second"
+    And the middleware should have recorded "request first, response first, request first, response first"
+
+  Scenario: a middleware error aborts the call
+    Given the configuration has the failing middleware broken
+    And the server responds with status 200
+    When calling the method getPets and recording the outcome
+    Then the outcome should contain "middleware broken failed"
+    And the server should have received 0 requests
+    And the middleware should have recorded "request broken"
@@ -0,0 +1,83 @@
+Feature: Multipart bodies
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/photos": {
+            "post": {
+              "operationId": "uploadPhoto",
+              "requestBody": {
+                "required": true,
+                "content": {
+                  "multipart/form-data": {
+                    "schema": {
+                      "type": "object",
+                      "required": ["photo"],
+                      "properties": {
+                        "photo": { "type": "string", "format": "binary" },
+                        "note": { "type": "string" },
+                        "metadata": { "$ref": "#/components/schemas/Metadata" }
+                      }
+                    },
+                    "encoding": {
+                      "note": { "contentType": "text/markdown" }
+                    }
+                  }
+                }
+              },
+              "responses": { "201": { "description": "created" } }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Metadata": {
+              "type": "object",
+              "properties": { "size": { "type": "integer" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 201
+
+  Scenario: every field is sent as a part of its own
+    When calling the method uploadPhoto with parameters and recording the outcome
+      """
+      [{
+        "photo": { "reader": "PNGDATA", "file_name": "tom.png", "content_type": "image/png" },
+        "note": "# Tom",
+        "metadata": { "size": 7 }
+      }]
+      """
+    Then the outcome should be "Created201"
+    And the last request should have 3 multipart parts
+    And the multipart part photo should have the content "PNGDATA"
+    And the multipart part photo should have the content type "image/png"
+    And the multipart part photo should have the file name "tom.png"
+    And the multipart part note should have the content "# Tom"
+    And the multipart part note should have the content type "text/markdown"
+    And the multipart part metadata should have the content '{"size":7}'
+    And the multipart part metadata should have the content type "application/json"
+
+  Scenario: files are sent with the content type the spec declares
+    When calling the method uploadPhoto with parameters and recording the outcome
+      """
+      [{ "photo": { "bytes": "PNGDATA" } }]
+      """
+    Then the outcome should be "Created201"
+    And the last request should have 1 multipart part
+    And the multipart part photo should have the content "PNGDATA"
+    And the multipart part photo should have the content type "application/octet-stream"
+
+  Scenario: files read from a path are named after it
+    When calling the method uploadPhoto with parameters and recording the outcome
+      """
+      [{ "photo": { "path": "Cargo.toml" } }]
+      """
+    Then the outcome should be "Created201"
+    And the multipart part photo should have the file name "Cargo.toml"
@@ -0,0 +1,52 @@
+Feature: OAuth2
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "security": [{ "oauth": [] }],
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        },
+        "components": {
+          "securitySchemes": {
+            "oauth": {
+              "type": "oauth2",
+              "flows": {
+                "clientCredentials": {
+                  "tokenUrl": "http://127.0.0.1:8888/token",
+                  "scopes": {}
+                }
+              }
+            }
+          }
+        }
+      }
+      """
+    And the oauth security scheme has the client credentials id and secret
+    And the server responds with status 200
+
+  Scenario: a token is fetched once and sent as a bearer token
+    Given the token endpoint then issues the token first expiring in 3600 seconds
+    When calling the method getPets and recording the outcome
+    And calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the server should have received 1 request to /token
+    And the last request should have a header Authorization with value "Bearer first"
+
+  Scenario: a token is renewed once it expires
+    Given the token endpoint then issues the token first expiring in 1 second
+    And the token endpoint then issues the token second expiring in 3600 seconds
+    When calling the method getPets and recording the outcome
+    And waiting 1000 milliseconds
+    And calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the server should have received 2 requests to /token
+    And the last request should have a header Authorization with value "Bearer second"
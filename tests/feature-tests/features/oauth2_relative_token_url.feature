@@ -0,0 +1,36 @@
+Feature: OAuth2 with a relative token url
+
+  Scenario: a relative token url is resolved against the base address
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "security": [{ "oauth": [] }],
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        },
+        "components": {
+          "securitySchemes": {
+            "oauth": {
+              "type": "oauth2",
+              "flows": {
+                "clientCredentials": { "tokenUrl": "/token", "scopes": {} }
+              }
+            }
+          }
+        }
+      }
+      """
+    And the oauth security scheme has the client credentials id and secret
+    And the server responds with status 200
+    And the token endpoint then issues the token first expiring in 3600 seconds
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the server should have received 1 request to /token
+    And the last request should have a header Authorization with value "Bearer first"
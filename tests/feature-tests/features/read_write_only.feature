@@ -0,0 +1,75 @@
+Feature: readOnly and writeOnly properties
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "post": {
+              "operationId": "addPet",
+              "requestBody": {
+                "required": true,
+                "content": {
+                  "application/json": {
+                    "schema": { "$ref": "#/components/schemas/Pet" }
+                  }
+                }
+              },
+              "responses": {
+                "201": {
+                  "description": "created",
+                  "content": {
+                    "application/json": {
+                      "schema": { "$ref": "#/components/schemas/Pet" }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Pet": {
+              "type": "object",
+              "required": ["id", "name", "password"],
+              "properties": {
+                "id": { "type": "integer", "readOnly": true },
+                "name": { "type": "string" },
+                "password": { "type": "string", "writeOnly": true }
+              }
+            }
+          }
+        }
+      }
+      """
+
+  Scenario: required readOnly and writeOnly properties are optional fields
+    Then Pet should have an optional property named id of type i64
+    And Pet should have a required property named name of type string
+    And Pet should have an optional property named password of type string
+
+  Scenario: readOnly properties are not sent in requests
+    Given the server responds with status 201 and body
+      """
+      { "id": 1, "name": "Tom" }
+      """
+    When calling the method addPet with parameters and recording the outcome
+      """
+      [{ "id": 1, "name": "Tom", "password": "secret" }]
+      """
+    Then the request should have a body with value {"name":"Tom","password":"secret"}
+
+  Scenario: writeOnly properties are not read from responses
+    Given the server responds with status 201 and body
+      """
+      { "id": 1, "name": "Tom", "password": "secret" }
+      """
+    When calling the method addPet with parameters and recording the outcome
+      """
+      [{ "name": "Tom" }]
+      """
+    Then the outcome should be 'Created201(Pet { id: RSome(1), name: "Tom", password: RNone })'
@@ -0,0 +1,70 @@
+Feature: Recursive models
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/nodes/root": {
+            "get": {
+              "operationId": "getRoot",
+              "responses": {
+                "200": {
+                  "description": "success",
+                  "content": {
+                    "application/json": {
+                      "schema": { "$ref": "#/components/schemas/Node" }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Node": {
+              "type": "object",
+              "properties": {
+                "value": { "type": "string" },
+                "next": { "$ref": "#/components/schemas/Node" },
+                "children": {
+                  "type": "array",
+                  "items": { "$ref": "#/components/schemas/Node" }
+                }
+              }
+            },
+            "Employee": {
+              "type": "object",
+              "properties": {
+                "manager": { "$ref": "#/components/schemas/Manager" }
+              }
+            },
+            "Manager": {
+              "type": "object",
+              "properties": {
+                "assistant": { "$ref": "#/components/schemas/Employee" }
+              }
+            }
+          }
+        }
+      }
+      """
+
+  Scenario: fields on a reference cycle are boxed
+    Then Node should have an optional property named next of type Box<Node>
+    And Employee should have an optional property named manager of type Box<Manager>
+    And Manager should have an optional property named assistant of type Box<Employee>
+
+  Scenario: arrays break reference cycles on their own
+    Then Node should have an optional property named children of type Vec<Node>
+
+  Scenario: recursive models are decoded
+    Given the server responds with status 200 and body
+      """
+      { "value": "a", "next": { "value": "b" } }
+      """
+    When calling the method getRoot and recording the outcome
+    Then the outcome should be 'Ok200(Node { value: RSome("a"), next: RSome(Node { value: RSome("b"), next: RNone, children: RNone }), children: RNone })'
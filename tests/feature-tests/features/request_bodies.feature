@@ -0,0 +1,116 @@
+Feature: Request bodies of every media type
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "post": {
+              "operationId": "createPet",
+              "requestBody": {
+                "required": true,
+                "content": {
+                  "application/vnd.pet+json": {
+                    "schema": { "$ref": "#/components/schemas/Pet" }
+                  }
+                }
+              },
+              "responses": { "201": { "description": "created" } }
+            }
+          },
+          "/notes": {
+            "post": {
+              "operationId": "createNote",
+              "requestBody": {
+                "content": {
+                  "application/json": { "schema": { "type": "string" } },
+                  "text/plain": { "schema": { "type": "string" } }
+                }
+              },
+              "responses": { "201": { "description": "created" } }
+            }
+          },
+          "/photos": {
+            "post": {
+              "operationId": "uploadPhoto",
+              "requestBody": {
+                "required": true,
+                "content": {
+                  "application/octet-stream": {
+                    "schema": { "type": "string", "format": "binary" }
+                  }
+                }
+              },
+              "responses": { "201": { "description": "created" } }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Pet": {
+              "type": "object",
+              "properties": { "name": { "type": "string" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 201
+
+  Scenario: a vendor json body is sent as json with its media type
+    When calling the method createPet with parameters and recording the outcome
+      """
+      [{ "name": "Tom" }]
+      """
+    Then the outcome should be "Created201"
+    And the last request should have the body '{"name":"Tom"}'
+    And the last request should have a header Content-Type with value "application/vnd.pet+json"
+
+  Scenario: a body is sent as the preferred media type by default
+    When calling the method createNote with parameters and recording the outcome
+      """
+      ["hello"]
+      """
+    Then the outcome should be "Created201"
+    And the last request should have the body '"hello"'
+    And the last request should have a header Content-Type with value "application/json"
+
+  Scenario: a text body is sent as the media type chosen, parameters and casing aside
+    Given the configuration sends request bodies as "Text/Plain; charset=utf-8"
+    When calling the method createNote with parameters and recording the outcome
+      """
+      ["hello"]
+      """
+    Then the outcome should be "Created201"
+    And the last request should have the body "hello"
+    And the last request should have a header Content-Type with value "Text/Plain; charset=utf-8"
+
+  Scenario: a media type the operation does not declare is an error
+    Given the configuration sends request bodies as "application/xml"
+    When calling the method createNote with parameters and recording the outcome
+      """
+      ["hello"]
+      """
+    Then the outcome should contain "the request body cannot be sent as application/xml"
+    And the server should have received 0 requests
+
+  Scenario: a request without its optional body has neither a body nor a Content-Type
+    When calling the method createNote with parameters and recording the outcome
+      """
+      [null]
+      """
+    Then the outcome should be "Created201"
+    And the last request should have the body ""
+    And the last request should not have a header Content-Type
+
+  Scenario: a binary body is sent as is
+    When calling the method uploadPhoto with parameters and recording the outcome
+      """
+      ["PNGDATA"]
+      """
+    Then the outcome should be "Created201"
+    And the last request should have the body "PNGDATA"
+    And the last request should have a header Content-Type with value "application/octet-stream"
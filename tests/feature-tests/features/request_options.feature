@@ -0,0 +1,73 @@
+Feature: Request options
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "parameters": [
+                { "name": "limit", "in": "query", "schema": { "type": "integer" } }
+              ],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        }
+      }
+      """
+
+  Scenario: headers and query parameters are added to those of the operation
+    Given the configuration has the request options
+      """
+      { "headers": [["X-Trace", "abc"]], "query": [["page", "2"]] }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      [5]
+      """
+    Then the outcome should be "Ok200"
+    And the requested URL should be http://localhost/pets?limit=5&page=2
+    And the last request should have a header X-Trace with value "abc"
+
+  Scenario: the base url replaces the one of the configuration
+    Given the configuration has the request options
+      """
+      { "base_url": "http://127.0.0.1:8888/v2" }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      [null]
+      """
+    Then the outcome should be "Ok200"
+    And the requested URL should be http://localhost/v2/pets
+
+  Scenario: a request which takes longer than the timeout fails
+    Given the configuration has the request options
+      """
+      { "timeout_ms": 100 }
+      """
+    And the server responds with status 200 after 1000 milliseconds
+    When calling the method getPets with parameters and recording the outcome
+      """
+      [null]
+      """
+    Then the outcome should contain "request timed out"
+
+  Scenario: a cancelled call is not sent
+    Given the configuration has the request options
+      """
+      { "cancelled": true }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      [null]
+      """
+    Then the outcome should be "error: request cancelled"
+    And the server should have received 0 requests
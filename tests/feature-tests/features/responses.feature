@@ -0,0 +1,147 @@
+Feature: Responses of every status
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pet": {
+            "get": {
+              "operationId": "getPet",
+              "responses": {
+                "200": {
+                  "description": "success",
+                  "content": {
+                    "application/json": {
+                      "schema": { "$ref": "#/components/schemas/Pet" }
+                    }
+                  }
+                },
+                "404": { "description": "not found" },
+                "4XX": {
+                  "description": "client error",
+                  "content": {
+                    "application/json": {
+                      "schema": { "$ref": "#/components/schemas/Problem" }
+                    }
+                  }
+                }
+              }
+            }
+          },
+          "/count": {
+            "get": {
+              "operationId": "getCount",
+              "responses": {
+                "200": {
+                  "description": "success",
+                  "content": {
+                    "application/json": { "schema": { "type": "integer" } },
+                    "text/plain": { "schema": { "type": "integer" } }
+                  }
+                }
+              }
+            }
+          },
+          "/owner": {
+            "get": {
+              "operationId": "getOwner",
+              "responses": {
+                "200": {
+                  "description": "success",
+                  "content": {
+                    "text/plain": { "schema": { "type": "string" } }
+                  }
+                },
+                "default": {
+                  "description": "anything else",
+                  "content": {
+                    "application/json": {
+                      "schema": { "$ref": "#/components/schemas/Problem" }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Pet": {
+              "type": "object",
+              "properties": { "name": { "type": "string" } }
+            },
+            "Problem": {
+              "type": "object",
+              "properties": { "message": { "type": "string" } }
+            }
+          }
+        }
+      }
+      """
+
+  Scenario: a documented status is decoded into its own variant
+    Given the server responds with status 200 and body
+      """
+      { "name": "Tom" }
+      """
+    When calling the method getPet and recording the outcome
+    Then the outcome should be 'Ok200(Pet { name: RSome("Tom") })'
+
+  Scenario: a documented status without content has a variant without data
+    Given the server responds with status 404
+    When calling the method getPet and recording the outcome
+    Then the outcome should be "NotFound404"
+
+  Scenario: a status range holds the actual status
+    Given the server responds with status 409 and body
+      """
+      { "message": "taken" }
+      """
+    When calling the method getPet and recording the outcome
+    Then the outcome should be 'ClientError4XX(409, Problem { message: RSome("taken") })'
+
+  Scenario: an undocumented status is an error
+    Given the server responds with status 500 and body
+      """
+      { "message": "down" }
+      """
+    When calling the method getPet and recording the outcome
+    Then the outcome should contain "unexpected response status 500"
+
+  Scenario: the default response takes undocumented success statuses
+    Given the server responds with status 202 and body
+      """
+      { "message": "accepted" }
+      """
+    When calling the method getOwner and recording the outcome
+    Then the outcome should be 'Default(202, Problem { message: RSome("accepted") })'
+
+  Scenario: error statuses which only the default response documents are errors
+    Given the server responds with status 500 and body
+      """
+      { "message": "down" }
+      """
+    When calling the method getOwner and recording the outcome
+    Then the outcome should contain "unexpected response status 500"
+
+  Scenario: every declared media type is accepted
+    Given the server responds with status 200 and body
+      """
+      42
+      """
+    When calling the method getCount and recording the outcome
+    Then the outcome should be "Ok200(42)"
+    And the last request should have a header Accept with value "application/json, text/plain"
+
+  Scenario: a scalar sent as text is parsed
+    Given the server responds with status 200 and the text/plain body "42"
+    When calling the method getCount and recording the outcome
+    Then the outcome should be "Ok200(42)"
+
+  Scenario: a body of a media type the response does not declare is an error
+    Given the server responds with status 200 and the text/html body "<p>Tom</p>"
+    When calling the method getPet and recording the outcome
+    Then the outcome should contain "unexpected content type text/html of the response of status 200"
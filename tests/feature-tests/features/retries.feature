@@ -0,0 +1,53 @@
+Feature: Retries
+
+  Background:
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "responses": { "200": { "description": "success" } }
+            },
+            "post": {
+              "operationId": "createPet",
+              "responses": { "201": { "description": "created" } }
+            }
+          }
+        }
+      }
+      """
+    And the configuration retries 3 times with a backoff of 100 milliseconds
+
+  Scenario: idempotent requests are retried with an exponential backoff
+    Given the server responds with status 200
+    And the server first responds 2 times with status 503
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the server should have received 3 requests
+    And the call should have taken at least 300 milliseconds
+
+  Scenario: requests are retried no more often than the policy allows
+    Given the server responds with status 200
+    And the server first responds 5 times with status 503
+    When calling the method getPets and recording the outcome
+    Then the outcome should contain "503"
+    And the server should have received 3 requests
+
+  Scenario: the delay which Retry-After asks for is waited
+    Given the server responds with status 200
+    And the server first responds 1 time with status 429 and a Retry-After of 1 second
+    When calling the method getPets and recording the outcome
+    Then the outcome should be "Ok200"
+    And the server should have received 2 requests
+    And the call should have taken at least 1000 milliseconds
+
+  Scenario: requests which are not idempotent are not retried
+    Given the server responds with status 201
+    And the server first responds 1 time with status 503
+    When calling the method createPet and recording the outcome
+    Then the outcome should contain "503"
+    And the server should have received 1 request
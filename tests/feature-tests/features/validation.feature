@@ -0,0 +1,206 @@
+Feature: Request validation
+
+  Scenario: a parameter which violates its constraints is not sent
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "parameters": [
+                {
+                  "name": "name",
+                  "in": "query",
+                  "required": true,
+                  "schema": { "type": "string", "maxLength": 5 }
+                }
+              ],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      ["Garfield"]
+      """
+    Then the outcome should contain "must be at most 5 characters long (maxLength)"
+    And the server should have received 0 requests
+
+  Scenario: a parameter which satisfies its constraints is sent
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "parameters": [
+                {
+                  "name": "name",
+                  "in": "query",
+                  "required": true,
+                  "schema": { "type": "string", "maxLength": 5 }
+                }
+              ],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      ["Odie"]
+      """
+    Then the outcome should be "Ok200"
+    And the server should have received 1 request
+
+  Scenario: a body which violates the constraints of its model is not sent
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "post": {
+              "operationId": "addPet",
+              "requestBody": {
+                "required": true,
+                "content": {
+                  "application/json": {
+                    "schema": { "$ref": "#/components/schemas/Pet" }
+                  }
+                }
+              },
+              "responses": { "201": { "description": "created" } }
+            }
+          }
+        },
+        "components": {
+          "schemas": {
+            "Pet": {
+              "type": "object",
+              "required": ["name"],
+              "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "age": { "type": "integer", "minimum": 0 }
+              }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 201
+    When calling the method addPet with parameters and recording the outcome
+      """
+      [{ "name": "", "age": -1 }]
+      """
+    Then the outcome should contain "must be at least 1 characters long (minLength)"
+    And the outcome should contain "(minimum)"
+    And the server should have received 0 requests
+
+  Scenario: a parameter which does not match its pattern is not sent
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "parameters": [
+                {
+                  "name": "name",
+                  "in": "query",
+                  "required": true,
+                  "schema": { "type": "string", "pattern": "^[a-z]+[0-9]$" }
+                }
+              ],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      ["odie"]
+      """
+    Then the outcome should contain "must match the pattern ^[a-z]+[0-9]$ (pattern)"
+    And the server should have received 0 requests
+
+  Scenario: a parameter which matches its pattern is sent
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "parameters": [
+                {
+                  "name": "name",
+                  "in": "query",
+                  "required": true,
+                  "schema": { "type": "string", "pattern": "^[a-z]+[0-9]$" }
+                }
+              ],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      ["odie2"]
+      """
+    Then the outcome should be "Ok200"
+    And the server should have received 1 request
+
+  Scenario: a pattern which the regex crate cannot compile is reported
+    Given an API with the following specification
+      """
+      {
+        "openapi": "3.0.0",
+        "info": { "title": "test", "version": "0.0.0" },
+        "paths": {
+          "/pets": {
+            "get": {
+              "operationId": "getPets",
+              "parameters": [
+                {
+                  "name": "name",
+                  "in": "query",
+                  "required": true,
+                  "schema": { "type": "string", "pattern": "^(?!admin)" }
+                }
+              ],
+              "responses": { "200": { "description": "success" } }
+            }
+          }
+        }
+      }
+      """
+    And the server responds with status 200
+    When calling the method getPets with parameters and recording the outcome
+      """
+      ["odie"]
+      """
+    Then the outcome should contain "the pattern ^(?!admin) is not supported (pattern)"
+    And the server should have received 0 requests
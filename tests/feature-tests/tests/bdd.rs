@@ -28,6 +28,16 @@ pub struct ForgeWorld {
     last_string_response: Option<RString>,
     last_object_response: Option<FFISafeTuple<FFIObject>>,
     last_fn_call_sign: Option<FnSignatureInformation>,
+    last_outcome: Option<RString>,
+    error_for_status: Option<bool>,
+    tokens: Vec<(String, String)>,
+    client_credentials: Vec<(String, String, String)>,
+    retry: Option<(u32, u64)>,
+    content_type: Option<String>,
+    request_options: Option<String>,
+    credentials_prefix: Option<String>,
+    middleware: Vec<(String, bool)>,
+    last_call_duration: Option<std::time::Duration>,
 }
 
 impl ForgeWorld {
@@ -42,6 +52,16 @@ impl ForgeWorld {
             last_string_response: None,
             last_object_response: None,
             last_fn_call_sign: None,
+            last_outcome: None,
+            error_for_status: None,
+            tokens: Vec::new(),
+            client_credentials: Vec::new(),
+            retry: None,
+            content_type: None,
+            request_options: None,
+            credentials_prefix: None,
+            middleware: Vec::new(),
+            last_call_duration: None,
         }
     }
 
@@ -65,6 +85,30 @@ impl ForgeWorld {
         if let Some(idx) = server_idx {
             FFICaller::run_config_idx_change(self, idx)?;
         }
+        if let Some(error_for_status) = self.error_for_status {
+            FFICaller::run_config_set_error_for_status(self, error_for_status)?;
+        }
+        if let Some((max_attempts, initial_backoff_ms)) = self.retry {
+            FFICaller::run_config_set_retry(self, max_attempts, initial_backoff_ms)?;
+        }
+        for (name, fail) in self.middleware.clone() {
+            FFICaller::run_config_add_middleware(self, &name, fail)?;
+        }
+        if let Some(prefix) = self.credentials_prefix.clone() {
+            FFICaller::run_config_set_env_credentials(self, &prefix)?;
+        }
+        if let Some(request_options) = self.request_options.clone() {
+            FFICaller::run_config_set_request_options(self, &request_options)?;
+        }
+        if let Some(content_type) = self.content_type.clone() {
+            FFICaller::run_config_set_content_type(self, &content_type)?;
+        }
+        for (scheme, token) in self.tokens.clone() {
+            FFICaller::run_config_set_token(self, &scheme, &token)?;
+        }
+        for (scheme, client_id, client_secret) in self.client_credentials.clone() {
+            FFICaller::run_config_set_client_credentials(self, &scheme, &client_id, &client_secret)?;
+        }
         let http_client = FFICaller::get_http_client(self)?;
         self.http_client = Some(http_client);
         if let Some(tag) = tag {
@@ -1,5 +1,5 @@
 use abi_stable::std_types::RString;
-use anyhow::{ bail, Ok, Result };
+use anyhow::{ bail, Context, Ok, Result };
 use libloading::{ Library, Symbol };
 
 use crate::data::{ FFISafeTuple, FnSignatureInformation, ObjectTypeInformation };
@@ -53,6 +53,190 @@ impl FFICaller {
         }
     }
 
+    pub fn run_config_set_error_for_status(
+        w: &mut ForgeWorld,
+        error_for_status: bool
+    ) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, bool) -> Box<Configuration>
+                > = library.get(b"c_config_set_error_for_status")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, error_for_status));
+                    Ok(())
+                } else {
+                    bail!("run_config_set_error_for_status cfg")
+                }
+            }
+        } else {
+            bail!("run_config_set_error_for_status")
+        }
+    }
+
+    pub fn run_config_set_retry(
+        w: &mut ForgeWorld,
+        max_attempts: u32,
+        initial_backoff_ms: u64
+    ) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, u32, u64) -> Box<Configuration>
+                > = library.get(b"c_config_set_retry")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, max_attempts, initial_backoff_ms));
+                    Ok(())
+                } else {
+                    bail!("run_config_set_retry cfg")
+                }
+            }
+        } else {
+            bail!("run_config_set_retry")
+        }
+    }
+
+    pub fn run_config_set_request_options(w: &mut ForgeWorld, request_options: &str) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, RString) -> Box<Configuration>
+                > = library.get(b"c_config_set_request_options")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, request_options.into()));
+                    Ok(())
+                } else {
+                    bail!("run_config_set_request_options cfg")
+                }
+            }
+        } else {
+            bail!("run_config_set_request_options")
+        }
+    }
+
+    pub fn run_config_set_env_credentials(w: &mut ForgeWorld, prefix: &str) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, RString) -> Box<Configuration>
+                > = library.get(b"c_config_set_env_credentials")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, prefix.into()));
+                    Ok(())
+                } else {
+                    bail!("run_config_set_env_credentials cfg")
+                }
+            }
+        } else {
+            bail!("run_config_set_env_credentials")
+        }
+    }
+
+    pub fn run_config_add_middleware(w: &mut ForgeWorld, name: &str, fail: bool) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, RString, bool) -> Box<Configuration>
+                > = library.get(b"c_config_add_middleware")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, name.into(), fail));
+                    Ok(())
+                } else {
+                    bail!("run_config_add_middleware cfg")
+                }
+            }
+        } else {
+            bail!("run_config_add_middleware")
+        }
+    }
+
+    pub fn get_middleware_log(w: &mut ForgeWorld) -> Result<RString> {
+        if let Some(library) = &w.library {
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<extern "C" fn() -> RString> = library.get(b"c_middleware_log")?;
+                Ok(func())
+            }
+        } else {
+            bail!("get_middleware_log")
+        }
+    }
+
+    pub fn run_config_set_content_type(w: &mut ForgeWorld, content_type: &str) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, RString) -> Box<Configuration>
+                > = library.get(b"c_config_set_content_type")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, content_type.into()));
+                    Ok(())
+                } else {
+                    bail!("run_config_set_content_type cfg")
+                }
+            }
+        } else {
+            bail!("run_config_set_content_type")
+        }
+    }
+
+    pub fn run_config_set_token(w: &mut ForgeWorld, scheme: &str, token: &str) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, RString, RString) -> Box<Configuration>
+                > = library.get(b"c_config_set_token")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, scheme.into(), token.into()));
+                    Ok(())
+                } else {
+                    bail!("run_config_set_token cfg")
+                }
+            }
+        } else {
+            bail!("run_config_set_token")
+        }
+    }
+
+    pub fn run_config_set_client_credentials(
+        w: &mut ForgeWorld,
+        scheme: &str,
+        client_id: &str,
+        client_secret: &str
+    ) -> Result<()> {
+        if let Some(library) = &w.library {
+            let config = w.config.take();
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let func: Symbol<
+                    extern "C" fn(Box<Configuration>, RString, RString, RString) -> Box<Configuration>
+                > = library.get(b"c_config_set_client_credentials")?;
+                if let Some(config) = config {
+                    w.config = Some(func(config, scheme.into(), client_id.into(), client_secret.into()));
+                    Ok(())
+                } else {
+                    bail!("run_config_set_client_credentials cfg")
+                }
+            }
+        } else {
+            bail!("run_config_set_client_credentials")
+        }
+    }
+
     pub fn get_http_client(w: &mut ForgeWorld) -> Result<Box<Client>> {
         if let Some(library) = &w.library {
             // SAFETY: using correct library ensure method's existance and correctness.
@@ -186,12 +370,13 @@ impl FFICaller {
             // SAFETY: using correct library ensure method's existance and correctness.
             unsafe {
                 let func: Symbol<
-                    extern "C" fn(Box<ApiClient>) -> Box<ForgeResponse<T>>
+                    extern "C" fn(Box<ApiClient>) -> Option<Box<ForgeResponse<T>>>
                 > = library.get(c_method_bytes)?;
                 let api_client = w.api_client.take();
                 if let Some(api_client) = api_client {
-                    let ret = func(api_client);
-                    Ok(ret)
+                    func(api_client).with_context(|| {
+                        format!("{} returned no response", c_method)
+                    })
                 } else {
                     bail!("run_method_no_params_with_return api_client")
                 }
@@ -213,12 +398,13 @@ impl FFICaller {
             // SAFETY: using correct library ensure method's existance and correctness.
             unsafe {
                 let func: Symbol<
-                    extern "C" fn(Box<ApiClient>, T) -> Box<ForgeResponse<U>>
+                    extern "C" fn(Box<ApiClient>, T) -> Option<Box<ForgeResponse<U>>>
                 > = library.get(c_method_bytes)?;
                 let api_client = w.api_client.take();
                 if let Some(api_client) = api_client {
-                    let ret = func(api_client, arg_1);
-                    Ok(ret)
+                    func(api_client, arg_1).with_context(|| {
+                        format!("{} returned no response", c_method)
+                    })
                 } else {
                     bail!("run_method_one_param api_client")
                 }
@@ -240,12 +426,13 @@ impl FFICaller {
             // SAFETY: using correct library ensure method's existance and correctness.
             unsafe {
                 let func: Symbol<
-                    extern "C" fn(Box<ApiClient>, RString) -> Box<ForgeResponse<T>>
+                    extern "C" fn(Box<ApiClient>, RString) -> Option<Box<ForgeResponse<T>>>
                 > = library.get(c_method_bytes)?;
                 let api_client = w.api_client.take();
                 if let Some(api_client) = api_client {
-                    let ret = func(api_client, arg_1);
-                    Ok(ret)
+                    func(api_client, arg_1).with_context(|| {
+                        format!("{} returned no response", c_method)
+                    })
                 } else {
                     bail!("run_method_one_serialized_param api_client")
                 }
@@ -268,12 +455,13 @@ impl FFICaller {
             // SAFETY: using correct library ensure method's existance and correctness.
             unsafe {
                 let func: Symbol<
-                    extern "C" fn(Box<ApiClient>, T, U) -> Box<ForgeResponse<V>>
+                    extern "C" fn(Box<ApiClient>, T, U) -> Option<Box<ForgeResponse<V>>>
                 > = library.get(c_method_bytes)?;
                 let api_client = w.api_client.take();
                 if let Some(api_client) = api_client {
-                    let ret = func(api_client, arg_1, arg_2);
-                    Ok(ret)
+                    func(api_client, arg_1, arg_2).with_context(|| {
+                        format!("{} returned no response", c_method)
+                    })
                 } else {
                     bail!("run_method_two_params api_client")
                 }
@@ -297,12 +485,13 @@ impl FFICaller {
             // SAFETY: using correct library ensure method's existance and correctness.
             unsafe {
                 let func: Symbol<
-                    extern "C" fn(Box<ApiClient>, T, U, V) -> Box<ForgeResponse<W>>
+                    extern "C" fn(Box<ApiClient>, T, U, V) -> Option<Box<ForgeResponse<W>>>
                 > = library.get(c_method_bytes)?;
                 let api_client = w.api_client.take();
                 if let Some(api_client) = api_client {
-                    let ret = func(api_client, arg_1, arg_2, arg_3);
-                    Ok(ret)
+                    func(api_client, arg_1, arg_2, arg_3).with_context(|| {
+                        format!("{} returned no response", c_method)
+                    })
                 } else {
                     bail!("run_method_three_params api_client")
                 }
@@ -312,6 +501,53 @@ impl FFICaller {
         }
     }
 
+    // the outcome functions take every parameter serialized and borrow the api client
+    pub fn run_method_for_outcome(
+        w: &mut ForgeWorld,
+        api_client_name: &str,
+        method_name: &str,
+        mut params: Vec<RString>
+    ) -> Result<RString> {
+        let c_method = format!("c_{}_{}_outcome", api_client_name, method_name);
+        let c_method_bytes = c_method.as_bytes();
+        if let (Some(library), Some(api_client)) = (&w.library, w.api_client.as_deref()) {
+            // SAFETY: using correct library ensure method's existance and correctness.
+            unsafe {
+                let outcome = match params.len() {
+                    0 => {
+                        let func: Symbol<extern "C" fn(&ApiClient) -> RString> =
+                            library.get(c_method_bytes)?;
+                        func(api_client)
+                    }
+                    1 => {
+                        let func: Symbol<extern "C" fn(&ApiClient, RString) -> RString> =
+                            library.get(c_method_bytes)?;
+                        func(api_client, params.remove(0))
+                    }
+                    2 => {
+                        let func: Symbol<
+                            extern "C" fn(&ApiClient, RString, RString) -> RString
+                        > = library.get(c_method_bytes)?;
+                        let el1 = params.remove(0);
+                        func(api_client, el1, params.remove(0))
+                    }
+                    3 => {
+                        let func: Symbol<
+                            extern "C" fn(&ApiClient, RString, RString, RString) -> RString
+                        > = library.get(c_method_bytes)?;
+                        let el1 = params.remove(0);
+                        let el2 = params.remove(0);
+                        func(api_client, el1, el2, params.remove(0))
+                    }
+                    _ => bail!("run_method_for_outcome too many arguments"),
+                };
+                Ok(outcome)
+            }
+        } else {
+            bail!("run_method_for_outcome")
+        }
+    }
+
     pub fn model_get_type_information(
         w: &mut ForgeWorld,
         struct_snake_case: &str
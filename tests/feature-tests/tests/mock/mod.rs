@@ -2,6 +2,7 @@ use anyhow::{ Context, Result };
 use wiremock::{ matchers, Mock, MockServer, ResponseTemplate };
 
 use once_cell::sync::OnceCell;
+use serde_json::json;
 
 static SERVER: OnceCell<MockServer> = OnceCell::new();
 
@@ -69,6 +70,66 @@ impl ForgeMockServer {
         Ok(())
     }
 
+    // responds to any number of requests, the feature asserts how many were made
+    pub async fn set_mock_with_status(status: u16, body: Option<&str>) -> Result<()> {
+        ForgeMockServer::set_mock_with_content_type(status, body, "application/json").await
+    }
+
+    pub async fn set_mock_with_content_type(
+        status: u16,
+        body: Option<&str>,
+        content_type: &str
+    ) -> Result<()> {
+        ForgeMockServer::reset_server().await?;
+        let server = ForgeMockServer::get_server()?;
+        let mut response = ResponseTemplate::new(status);
+        if let Some(body) = body {
+            response = response.set_body_raw(body, content_type);
+        }
+        Mock::given(matchers::any()).respond_with(response).mount(server).await;
+
+        Ok(())
+    }
+
+    // answers the first requests, mounted alongside the mocks already set
+    pub async fn add_leading_status_mock(times: u64, status: u16, retry_after: Option<u64>) -> Result<()> {
+        let server = ForgeMockServer::get_server()?;
+        let mut response = ResponseTemplate::new(status);
+        if let Some(retry_after) = retry_after {
+            response = response.append_header("Retry-After", retry_after.to_string().as_str());
+        }
+        Mock::given(matchers::any())
+            .respond_with(response)
+            .up_to_n_times(times)
+            .with_priority(1)
+            .mount(server).await;
+
+        Ok(())
+    }
+
+    // answers a single token request, mounted alongside the mocks already set
+    pub async fn add_token_mock(access_token: &str, expires_in: u64) -> Result<()> {
+        let server = ForgeMockServer::get_server()?;
+        let body = json!({ "access_token": access_token, "token_type": "Bearer", "expires_in": expires_in });
+        Mock::given(matchers::path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(server).await;
+
+        Ok(())
+    }
+
+    pub async fn set_mock_with_delay(status: u16, delay: u64) -> Result<()> {
+        ForgeMockServer::reset_server().await?;
+        let server = ForgeMockServer::get_server()?;
+        Mock::given(matchers::any())
+            .respond_with(ResponseTemplate::new(status).set_delay(std::time::Duration::from_millis(delay)))
+            .mount(server).await;
+
+        Ok(())
+    }
+
     pub async fn reset_server() -> Result<()> {
         let server = ForgeMockServer::get_server()?;
         server.reset().await;
@@ -3,6 +3,7 @@ use cucumber::{ given, gherkin::Step };
 
 use crate::{
     ForgeWorld,
+    mock::ForgeMockServer,
     util::{ hash_an_object, write_schema_to_file, forge, compile_generated_api },
 };
 
@@ -21,4 +22,131 @@ async fn given_api_specification(w: &mut ForgeWorld, step: &Step) -> Result<()>
     compile_generated_api(w.library_name_modifier.context("library modifier")?).await?;
     w.set_library()?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[given(expr = "the server responds with status {int}")]
+async fn server_responds_with_status(_w: &mut ForgeWorld, status: u16) -> Result<()> {
+    ForgeMockServer::set_mock_with_status(status, None).await?;
+    Ok(())
+}
+
+#[given(expr = "the server responds with status {int} and body")]
+async fn server_responds_with_status_and_body(
+    _w: &mut ForgeWorld,
+    status: u16,
+    step: &Step
+) -> Result<()> {
+    let body = step.docstring().context("response body not found")?.trim();
+    ForgeMockServer::set_mock_with_status(status, Some(body)).await?;
+    Ok(())
+}
+
+#[given(expr = "the server responds with status {int} and the {word} body {string}")]
+async fn server_responds_with_content_type(
+    _w: &mut ForgeWorld,
+    status: u16,
+    content_type: String,
+    body: String
+) -> Result<()> {
+    ForgeMockServer::set_mock_with_content_type(status, Some(&body), &content_type).await?;
+    Ok(())
+}
+
+#[given(expr = "the configuration does not treat statuses as errors")]
+async fn statuses_are_not_errors(w: &mut ForgeWorld) -> Result<()> {
+    w.error_for_status = Some(false);
+    Ok(())
+}
+
+#[given(expr = "the {word} security scheme has the token {word}")]
+async fn security_scheme_token(w: &mut ForgeWorld, scheme: String, token: String) -> Result<()> {
+    w.tokens.push((scheme, token));
+    Ok(())
+}
+
+#[given(expr = "the {word} security scheme has the client credentials {word} and {word}")]
+async fn security_scheme_client_credentials(
+    w: &mut ForgeWorld,
+    scheme: String,
+    client_id: String,
+    client_secret: String
+) -> Result<()> {
+    w.client_credentials.push((scheme, client_id, client_secret));
+    Ok(())
+}
+
+#[given(expr = "the token endpoint then issues the token {word} expiring in {int} second(s)")]
+async fn token_endpoint_issues(_w: &mut ForgeWorld, access_token: String, expires_in: u64) -> Result<()> {
+    ForgeMockServer::add_token_mock(&access_token, expires_in).await?;
+    Ok(())
+}
+
+#[given(expr = "the configuration retries {int} times with a backoff of {int} milliseconds")]
+async fn configuration_retries(w: &mut ForgeWorld, max_attempts: u32, initial_backoff_ms: u64) -> Result<()> {
+    w.retry = Some((max_attempts, initial_backoff_ms));
+    Ok(())
+}
+
+#[given(expr = "the server first responds {int} time(s) with status {int}")]
+async fn server_first_responds(_w: &mut ForgeWorld, times: u64, status: u16) -> Result<()> {
+    ForgeMockServer::add_leading_status_mock(times, status, None).await?;
+    Ok(())
+}
+
+#[given(
+    expr = "the server first responds {int} time(s) with status {int} and a Retry-After of {int} second(s)"
+)]
+async fn server_first_responds_with_retry_after(
+    _w: &mut ForgeWorld,
+    times: u64,
+    status: u16,
+    retry_after: u64
+) -> Result<()> {
+    ForgeMockServer::add_leading_status_mock(times, status, Some(retry_after)).await?;
+    Ok(())
+}
+
+#[given(expr = "the configuration sends request bodies as {string}")]
+async fn configuration_content_type(w: &mut ForgeWorld, content_type: String) -> Result<()> {
+    w.content_type = Some(content_type);
+    Ok(())
+}
+
+#[given(expr = "the configuration has the request options")]
+async fn configuration_request_options(w: &mut ForgeWorld, step: &Step) -> Result<()> {
+    let request_options = step.docstring().context("request options not found")?.trim();
+    // checked here, the library leaves the options as they are when they are not json
+    serde_json::from_str::<serde_json::Value>(request_options)?;
+    w.request_options = Some(request_options.to_owned());
+    Ok(())
+}
+
+#[given(expr = "the server responds with status {int} after {int} milliseconds")]
+async fn server_responds_after(_w: &mut ForgeWorld, status: u16, delay: u64) -> Result<()> {
+    ForgeMockServer::set_mock_with_delay(status, delay).await?;
+    Ok(())
+}
+
+#[given(expr = "the configuration reads credentials from the environment with the prefix {word}")]
+async fn configuration_env_credentials(w: &mut ForgeWorld, prefix: String) -> Result<()> {
+    w.credentials_prefix = Some(prefix);
+    Ok(())
+}
+
+#[given(expr = "the environment variable {word} is {string}")]
+async fn environment_variable(_w: &mut ForgeWorld, name: String, value: String) -> Result<()> {
+    std::env::set_var(name, value);
+    Ok(())
+}
+
+#[given(expr = "the configuration has the middleware {word}")]
+async fn configuration_middleware(w: &mut ForgeWorld, name: String) -> Result<()> {
+    w.middleware.push((name, false));
+    Ok(())
+}
+
+#[given(expr = "the configuration has the failing middleware {word}")]
+async fn configuration_failing_middleware(w: &mut ForgeWorld, name: String) -> Result<()> {
+    w.middleware.push((name, true));
+    Ok(())
+}
@@ -312,4 +312,194 @@ async fn api_client_with_tag_should_not_have_method(
     } else {
         bail!("The method exists");
     }
-}
\ No newline at end of file
+}
+
+#[then(expr = "the outcome should be {string}")]
+async fn outcome_should_be(w: &mut ForgeWorld, expected: String) -> Result<()> {
+    let outcome = w.last_outcome.as_ref().context("no outcome")?;
+    assert_eq!(outcome.as_str(), expected);
+    Ok(())
+}
+
+#[then(expr = "the outcome should contain {string}")]
+async fn outcome_should_contain(w: &mut ForgeWorld, expected: String) -> Result<()> {
+    let outcome = w.last_outcome.as_ref().context("no outcome")?;
+    assert!(outcome.contains(&expected), "{} does not contain {}", outcome, expected);
+    Ok(())
+}
+
+#[then(expr = "the server should have received {int} request(s)")]
+async fn server_should_have_received(_w: &mut ForgeWorld, expected: usize) -> Result<()> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    assert_eq!(requests.len(), expected);
+    Ok(())
+}
+
+#[then(expr = "the server should have received {int} request(s) to {word}")]
+async fn server_should_have_received_to(_w: &mut ForgeWorld, expected: usize, path: String) -> Result<()> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    let received = requests.iter().filter(|req| req.url.path() == path).count();
+    assert_eq!(received, expected);
+    Ok(())
+}
+
+#[then(expr = "the last request should have a header {word} with value {string}")]
+async fn last_request_should_have_header(_w: &mut ForgeWorld, name: String, expected: String) -> Result<()> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    let last_req = requests.last().context("no request")?;
+    let value = last_req.headers
+        .get(&HeaderName::from(name.to_lowercase().as_str()))
+        .context("no header")?
+        .last();
+    assert_eq!(value.as_str(), expected);
+    Ok(())
+}
+
+#[then(expr = "the last request should not have a header {word}")]
+async fn last_request_should_not_have_header(_w: &mut ForgeWorld, name: String) -> Result<()> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    let last_req = requests.last().context("no request")?;
+    assert!(last_req.headers.get(&HeaderName::from(name.to_lowercase().as_str())).is_none());
+    Ok(())
+}
+
+#[then(expr = "the last request should have the body {string}")]
+async fn last_request_should_have_body(_w: &mut ForgeWorld, expected: String) -> Result<()> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    let last_req = requests.last().context("no request")?;
+    assert_eq!(String::from_utf8_lossy(&last_req.body), expected);
+    Ok(())
+}
+
+#[then(expr = "the last request should have the header {word} with the values {string}")]
+async fn last_request_should_have_header_values(_w: &mut ForgeWorld, name: String, expected: String) -> Result<()> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    let last_req = requests.last().context("no request")?;
+    let values = last_req.headers
+        .get(&HeaderName::from(name.to_lowercase().as_str()))
+        .context("no header")?
+        .iter()
+        .map(|h| h.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(values.join(", "), expected);
+    Ok(())
+}
+
+#[then(expr = "the middleware should have recorded {string}")]
+async fn middleware_should_have_recorded(w: &mut ForgeWorld, expected: String) -> Result<()> {
+    let log = FFICaller::get_middleware_log(w)?;
+    assert_eq!(log.as_str(), expected);
+    Ok(())
+}
+
+#[then(expr = "the call should have taken at least {int} milliseconds")]
+async fn call_should_have_taken(w: &mut ForgeWorld, milliseconds: u64) -> Result<()> {
+    let duration = w.last_call_duration.context("no call")?;
+    assert!(duration.as_millis() >= milliseconds.into(), "the call took {:?}", duration);
+    Ok(())
+}
+
+#[then(expr = "the request should have a single cookie header with value {string}")]
+async fn request_should_have_single_cookie(_w: &mut ForgeWorld, expected: String) -> Result<()> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    let last_req = requests.last().context("no request")?;
+    let cookies = last_req.headers
+        .get(&HeaderName::from("cookie"))
+        .context("no cookie")?
+        .iter()
+        .map(|h| h.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(cookies, vec![expected.as_str()]);
+    Ok(())
+}
+
+// the parts of the multipart body of the last request, split at the boundary its
+// Content-Type declares, as their headers and content
+async fn last_multipart_parts() -> Result<Vec<(Vec<(String, String)>, String)>> {
+    let server = ForgeMockServer::get_server()?;
+    let requests = server.received_requests().await.context("Problem with received requests")?;
+    let last_req = requests.last().context("no request")?;
+    let content_type = last_req.headers
+        .get(&HeaderName::from("content-type"))
+        .context("no content type")?
+        .last()
+        .as_str()
+        .to_owned();
+    let boundary = content_type
+        .strip_prefix("multipart/form-data; boundary=")
+        .context("not a multipart body")?;
+    let body = String::from_utf8_lossy(&last_req.body).into_owned();
+    let delimiter = format!("--{}", boundary);
+    let mut parts = Vec::new();
+    for part in body.split(&delimiter).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+        let (headers, content) = part
+            .trim_start_matches("\r\n")
+            .split_once("\r\n\r\n")
+            .context("part without headers")?;
+        let headers = headers
+            .split("\r\n")
+            .filter_map(|header| header.split_once(": "))
+            .map(|(name, value)| (name.to_lowercase(), value.to_owned()))
+            .collect();
+        parts.push((headers, content.trim_end_matches("\r\n").to_owned()));
+    }
+    Ok(parts)
+}
+
+async fn last_multipart_part(name: &str) -> Result<(Vec<(String, String)>, String)> {
+    let disposition = format!("form-data; name=\"{}\"", name);
+    last_multipart_parts()
+        .await?
+        .into_iter()
+        .find(|(headers, _)| {
+            headers
+                .iter()
+                .any(|(header, value)| header == "content-disposition" && value.starts_with(&disposition))
+        })
+        .context("no such part")
+}
+
+fn part_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header == name)
+        .map(|(_, value)| value.as_str())
+}
+
+#[then(expr = "the last request should have {int} multipart part(s)")]
+async fn multipart_part_count(_w: &mut ForgeWorld, expected: usize) -> Result<()> {
+    assert_eq!(last_multipart_parts().await?.len(), expected);
+    Ok(())
+}
+
+#[then(expr = "the multipart part {word} should have the content {string}")]
+async fn multipart_part_content(_w: &mut ForgeWorld, name: String, expected: String) -> Result<()> {
+    let (_, content) = last_multipart_part(&name).await?;
+    assert_eq!(content, expected);
+    Ok(())
+}
+
+#[then(expr = "the multipart part {word} should have the content type {string}")]
+async fn multipart_part_content_type(_w: &mut ForgeWorld, name: String, expected: String) -> Result<()> {
+    let (headers, _) = last_multipart_part(&name).await?;
+    assert_eq!(part_header(&headers, "content-type"), Some(expected.as_str()));
+    Ok(())
+}
+
+#[then(expr = "the multipart part {word} should have the file name {string}")]
+async fn multipart_part_file_name(_w: &mut ForgeWorld, name: String, expected: String) -> Result<()> {
+    let (headers, _) = last_multipart_part(&name).await?;
+    let disposition = part_header(&headers, "content-disposition").context("no disposition")?;
+    assert!(disposition.ends_with(&format!("filename=\"{}\"", expected)), "{}", disposition);
+    Ok(())
+}
@@ -394,4 +394,44 @@ async fn call_method_with_server_responds_empty(
     w.last_object_response = Some(tuple);
     w.api_client_name = Some(api_client_name);
     Ok(())
-}
\ No newline at end of file
+}
+
+#[when(expr = "waiting {int} milliseconds")]
+async fn waiting(_w: &mut ForgeWorld, milliseconds: u64) -> Result<()> {
+    tokio::time::sleep(std::time::Duration::from_millis(milliseconds)).await;
+    Ok(())
+}
+
+#[when(expr = "calling the method {word} and recording the outcome")]
+async fn call_method_for_outcome(w: &mut ForgeWorld, method_name: String) -> Result<()> {
+    record_outcome(w, method_name, vec![])
+}
+
+#[when(expr = "calling the method {word} with parameters and recording the outcome")]
+async fn call_method_with_params_for_outcome(
+    w: &mut ForgeWorld,
+    method_name: String,
+    step: &Step
+) -> Result<()> {
+    // the parameters are a json array, each element is passed serialized
+    let raw_params = step.docstring().context("parameters not found")?.trim();
+    let params = serde_json::from_str::<Vec<Value>>(raw_params)?
+        .iter()
+        .map(|param| RString::from(param.to_string()))
+        .collect::<Vec<_>>();
+    record_outcome(w, method_name, params)
+}
+
+fn record_outcome(w: &mut ForgeWorld, method_name: String, params: Vec<RString>) -> Result<()> {
+    // make sure api_client exists
+    if w.api_client.is_none() {
+        w.set_reset_client(None, None)?;
+    }
+    let method_name = method_name.to_case(convert_case::Case::Snake);
+    let api_client_name = w.api_client_name.clone().context("No client name")?;
+    let started = std::time::Instant::now();
+    let outcome = FFICaller::run_method_for_outcome(w, &api_client_name, &method_name, params)?;
+    w.last_call_duration = Some(started.elapsed());
+    w.last_outcome = Some(outcome);
+    Ok(())
+}
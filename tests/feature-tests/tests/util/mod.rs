@@ -8,6 +8,8 @@ use tokio::process::Command;
 
 pub(crate) const FEATURES: &str = "tests/features";
 
+const LOCAL_FEATURES: &str = "features";
+
 const GENERATED_API_PARENT: &str = ".generated-apis";
 
 #[cfg(unix)]
@@ -77,10 +79,13 @@ pub async fn forge(modifier: u64) -> Result<()> {
     Ok(())
 }
 
+// The features of this generator alone sit next to those shared by every generator.
 pub async fn copy_feature_files() -> Result<()> {
-    let mut entries = tokio::fs::read_dir("../../../openapi-forge/features/").await?;
-    while let Some(entry) = entries.next_entry().await? {
-        tokio::fs::copy(entry.path(), Path::new(FEATURES).join(entry.file_name())).await?;
+    for dir in ["../../../openapi-forge/features/", LOCAL_FEATURES] {
+        let mut entries = tokio::fs::read_dir(dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            tokio::fs::copy(entry.path(), Path::new(FEATURES).join(entry.file_name())).await?;
+        }
     }
     Ok(())
 }